@@ -0,0 +1,70 @@
+use chrono::Local;
+use serde::{Deserialize, Serialize};
+use tauri::{AppHandle, Emitter};
+use tauri_plugin_notification::NotificationExt;
+
+use crate::db::logs::add_log;
+use crate::rules::engine::{evaluate, RuleEvent};
+use crate::rules::load_rules;
+
+// Alerts raised by backend tasks go through the same rules as pipeline events, the overlay is
+// driven by the frontend so overlay alerts are emitted for it to forward to XSOverlay
+const OVERLAY_EVENT: &str = "overlay-notification";
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct OverlayAlert {
+    pub title: String,
+    pub message: String,
+}
+
+/// Delivers an alert according to the notification rules, logging it under `log_type` if the rules allow it
+pub async fn dispatch_alert(
+    app: &AppHandle,
+    event: &RuleEvent,
+    title: &str,
+    message: &str,
+    log_type: Option<&str>,
+) {
+    let rules = load_rules(app).unwrap_or_else(|e| {
+        log::warn!("{}", e);
+        Vec::new()
+    });
+    let decision = evaluate(&rules, event, Local::now().time());
+
+    if decision.desktop {
+        if let Err(e) = app
+            .notification()
+            .builder()
+            .title(title)
+            .body(message)
+            .show()
+        {
+            log::warn!("Failed to show notification: {}", e);
+        }
+    }
+
+    if decision.overlay {
+        let alert = OverlayAlert {
+            title: title.to_string(),
+            message: message.to_string(),
+        };
+        if let Err(e) = app.emit(OVERLAY_EVENT, alert) {
+            log::warn!("Failed to emit overlay notification: {}", e);
+        }
+    }
+
+    if let (true, Some(log_type)) = (decision.log, log_type) {
+        if let Err(e) = add_log(
+            app,
+            log_type,
+            title,
+            event.user_id.as_deref(),
+            event.world_id.as_deref(),
+        )
+        .await
+        {
+            log::warn!("{}", e);
+        }
+    }
+}
@@ -0,0 +1,60 @@
+use std::collections::hash_map::RandomState;
+use std::collections::HashMap;
+use std::hash::{BuildHasher, Hasher};
+use std::sync::Mutex;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+use tauri::{AppHandle, Manager};
+
+// Destructive commands require a token from `request_confirmation_token`, so the frontend has to
+// explicitly confirm the action and target before anything is sent to VRChat
+const TOKEN_LIFETIME: Duration = Duration::from_secs(60);
+
+#[derive(Default)]
+pub struct ConfirmationTokens(Mutex<HashMap<String, (String, Instant)>>);
+
+fn token_key(action: &str, target: &str) -> String {
+    format!("{}:{}", action, target)
+}
+
+/// Issues a single use token that confirms `action` against `target`, valid for one minute
+#[tauri::command]
+pub fn request_confirmation_token(app: AppHandle, action: String, target: String) -> String {
+    let tokens = app.state::<ConfirmationTokens>();
+    let mut tokens = tokens.0.lock().unwrap();
+    tokens.retain(|_, (_, issued)| issued.elapsed() < TOKEN_LIFETIME);
+
+    let nanos = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_nanos())
+        .unwrap_or_default();
+    let mut hasher = RandomState::new().build_hasher();
+    hasher.write(token_key(&action, &target).as_bytes());
+    hasher.write_u128(nanos);
+    let token = format!("{:016x}", hasher.finish());
+
+    tokens.insert(token.clone(), (token_key(&action, &target), Instant::now()));
+    token
+}
+
+/// Consumes a token, failing if it was not issued for this action and target or has expired
+pub fn consume_confirmation_token(
+    app: &AppHandle,
+    token: &str,
+    action: &str,
+    target: &str,
+) -> Result<(), String> {
+    let tokens = app.state::<ConfirmationTokens>();
+    let mut tokens = tokens.0.lock().unwrap();
+
+    match tokens.remove(token) {
+        Some((key, issued))
+            if key == token_key(action, target) && issued.elapsed() < TOKEN_LIFETIME =>
+        {
+            Ok(())
+        }
+        _ => Err(format!(
+            "Confirmation token is not valid for {} on {}",
+            action, target
+        )),
+    }
+}
@@ -0,0 +1,59 @@
+use serde::{Deserialize, Serialize};
+use tauri::AppHandle;
+
+use crate::db::get_pool;
+
+/// A moderation action taken in a group from Spectre
+#[derive(Serialize, Deserialize, Debug, Clone, sqlx::FromRow)]
+#[serde(rename_all = "camelCase")]
+pub struct GroupModerationEntry {
+    pub id: i64,
+    pub group_id: String,
+    pub action: String,
+    pub target_user_id: Option<String>,
+    pub details: Option<String>,
+    pub created: String,
+}
+
+pub async fn record_group_action(
+    app: &AppHandle,
+    group_id: &str,
+    action: &str,
+    target_user_id: Option<&str>,
+    details: Option<&str>,
+) -> Result<(), String> {
+    let pool = get_pool(app).await?;
+
+    sqlx::query(
+        "INSERT INTO group_moderation_log (group_id, action, target_user_id, details, created) VALUES ($1, $2, $3, $4, Datetime('now', 'localtime'))",
+    )
+    .bind(group_id)
+    .bind(action)
+    .bind(target_user_id)
+    .bind(details)
+    .execute(&pool)
+    .await
+    .map_err(|e| format!("Failed to record group action: {}", e))?;
+
+    Ok(())
+}
+
+/// Gets the most recent group moderation actions, newest first
+#[tauri::command]
+pub async fn get_group_moderation_history(
+    app: AppHandle,
+    group_id: Option<String>,
+    limit: Option<u32>,
+) -> Result<Vec<GroupModerationEntry>, String> {
+    let pool = get_pool(&app).await?;
+
+    sqlx::query_as::<_, GroupModerationEntry>(
+        "SELECT id, group_id, action, target_user_id, details, created FROM group_moderation_log
+         WHERE $1 IS NULL OR group_id = $1 ORDER BY id DESC LIMIT $2",
+    )
+    .bind(group_id)
+    .bind(limit.unwrap_or(200))
+    .fetch_all(&pool)
+    .await
+    .map_err(|e| format!("Failed to get group moderation history: {}", e))
+}
@@ -0,0 +1,428 @@
+use serde::{Deserialize, Serialize};
+use sqlx::{Pool, QueryBuilder, Sqlite};
+use tauri::AppHandle;
+
+use crate::db::get_pool;
+use crate::types::avatar::Avatar;
+use crate::types::favorite::FavoriteType;
+use crate::types::world::World;
+use crate::web::vrc_avatar::get_vrc_avatar;
+use crate::web::vrc_favorites::{get_all_favorites, get_favorite_groups};
+use crate::web::vrc_world::get_vrc_world;
+
+// Local favorites have no size limit, they only exist in spectre.db
+
+/// A user defined list of world or avatar favorites
+#[derive(Serialize, Deserialize, Debug, Clone, sqlx::FromRow)]
+#[serde(rename_all = "camelCase")]
+pub struct LocalFavoriteList {
+    pub id: i64,
+    pub name: String,
+    #[serde(rename = "type")]
+    #[sqlx(rename = "type")]
+    pub favorite_type: String,
+    pub created_at: String,
+    pub count: i64,
+}
+
+/// A single local favorite, `name`, `author_name` and `thumbnail_url` are cached from the API
+#[derive(Serialize, Deserialize, Debug, Clone, sqlx::FromRow)]
+#[serde(rename_all = "camelCase")]
+pub struct LocalFavorite {
+    pub id: i64,
+    pub list_id: i64,
+    pub target_id: String,
+    #[sqlx(json)]
+    pub tags: Vec<String>,
+    pub notes: Option<String>,
+    pub name: Option<String>,
+    pub author_name: Option<String>,
+    pub thumbnail_url: Option<String>,
+    pub cached_at: Option<String>,
+    pub added_at: String,
+}
+
+struct FavoriteMetadata {
+    name: String,
+    author_name: String,
+    thumbnail_url: String,
+}
+
+fn check_favorite_type(favorite_type: FavoriteType) -> Result<(), String> {
+    match favorite_type {
+        FavoriteType::World | FavoriteType::Avatar => Ok(()),
+        FavoriteType::Friend => Err("Local favorites only support worlds and avatars".to_string()),
+    }
+}
+
+fn type_for_target(target_id: &str) -> Result<FavoriteType, String> {
+    if target_id.starts_with("wrld_") {
+        Ok(FavoriteType::World)
+    } else if target_id.starts_with("avtr_") {
+        Ok(FavoriteType::Avatar)
+    } else {
+        Err(format!("{} is not a world or avatar id", target_id))
+    }
+}
+
+/// Resolves the display metadata of a world or avatar through the API
+async fn resolve_metadata(app: &AppHandle, target_id: &str) -> Result<FavoriteMetadata, String> {
+    match type_for_target(target_id)? {
+        FavoriteType::World => {
+            let text = get_vrc_world(app.clone(), target_id.to_string()).await?;
+            let world = serde_json::from_str::<World>(&text)
+                .map_err(|e| format!("Failed to parse world: {}", e))?;
+
+            Ok(FavoriteMetadata {
+                name: world.name,
+                author_name: world.author_name,
+                thumbnail_url: world.thumbnail_image_url,
+            })
+        }
+        _ => {
+            let text = get_vrc_avatar(app.clone(), target_id.to_string()).await?;
+            let avatar = serde_json::from_str::<Avatar>(&text)
+                .map_err(|e| format!("Failed to parse avatar: {}", e))?;
+
+            Ok(FavoriteMetadata {
+                name: avatar.name,
+                author_name: avatar.author_name,
+                thumbnail_url: avatar.thumbnail_image_url,
+            })
+        }
+    }
+}
+
+async fn get_list(pool: &Pool<Sqlite>, list_id: i64) -> Result<LocalFavoriteList, String> {
+    sqlx::query_as::<_, LocalFavoriteList>(
+        "SELECT l.*, (SELECT COUNT(*) FROM local_favorites f WHERE f.list_id = l.id) AS count FROM local_favorite_lists l WHERE l.id = $1",
+    )
+    .bind(list_id)
+    .fetch_optional(pool)
+    .await
+    .map_err(|e| format!("Failed to get local favorite list: {}", e))?
+    .ok_or_else(|| format!("Local favorite list {} does not exist", list_id))
+}
+
+async fn get_favorite(pool: &Pool<Sqlite>, id: i64) -> Result<LocalFavorite, String> {
+    sqlx::query_as::<_, LocalFavorite>("SELECT * FROM local_favorites WHERE id = $1")
+        .bind(id)
+        .fetch_optional(pool)
+        .await
+        .map_err(|e| format!("Failed to get local favorite: {}", e))?
+        .ok_or_else(|| format!("Local favorite {} does not exist", id))
+}
+
+async fn find_list_by_name(
+    pool: &Pool<Sqlite>,
+    name: &str,
+    favorite_type: FavoriteType,
+) -> Result<Option<i64>, String> {
+    sqlx::query_scalar::<_, i64>(
+        "SELECT id FROM local_favorite_lists WHERE name = $1 AND type = $2",
+    )
+    .bind(name)
+    .bind(favorite_type.to_string())
+    .fetch_optional(pool)
+    .await
+    .map_err(|e| format!("Failed to get local favorite list: {}", e))
+}
+
+async fn insert_list(
+    pool: &Pool<Sqlite>,
+    name: &str,
+    favorite_type: FavoriteType,
+) -> Result<i64, String> {
+    let result = sqlx::query(
+        "INSERT INTO local_favorite_lists (name, type, created_at) VALUES ($1, $2, Datetime('now', 'localtime'))",
+    )
+    .bind(name)
+    .bind(favorite_type.to_string())
+    .execute(pool)
+    .await
+    .map_err(|e| format!("Failed to create local favorite list: {}", e))?;
+
+    Ok(result.last_insert_rowid())
+}
+
+/// Inserts a favorite, resolving its metadata first. Favorites already in the list are updated instead
+async fn upsert_favorite(
+    app: &AppHandle,
+    pool: &Pool<Sqlite>,
+    list: &LocalFavoriteList,
+    target_id: &str,
+    tags: &[String],
+    notes: Option<String>,
+) -> Result<i64, String> {
+    if type_for_target(target_id)?.to_string() != list.favorite_type {
+        return Err(format!(
+            "{} cannot be added to a {} list",
+            target_id, list.favorite_type
+        ));
+    }
+
+    // Metadata is best effort, private or deleted content can still be saved
+    let metadata = match resolve_metadata(app, target_id).await {
+        Ok(metadata) => Some(metadata),
+        Err(e) => {
+            log::warn!("Failed to resolve metadata for {}: {}", target_id, e);
+            None
+        }
+    };
+
+    let tags =
+        serde_json::to_string(tags).map_err(|e| format!("Failed to serialize tags: {}", e))?;
+
+    sqlx::query_scalar::<_, i64>(
+        "INSERT INTO local_favorites (list_id, target_id, tags, notes, name, author_name, thumbnail_url, cached_at, added_at)
+         VALUES ($1, $2, $3, $4, $5, $6, $7, CASE WHEN $5 IS NULL THEN NULL ELSE Datetime('now', 'localtime') END, Datetime('now', 'localtime'))
+         ON CONFLICT (list_id, target_id) DO UPDATE SET tags = CASE WHEN excluded.tags = '[]' THEN tags ELSE excluded.tags END, notes = COALESCE(excluded.notes, notes),
+           name = COALESCE(excluded.name, name), author_name = COALESCE(excluded.author_name, author_name),
+           thumbnail_url = COALESCE(excluded.thumbnail_url, thumbnail_url), cached_at = COALESCE(excluded.cached_at, cached_at)
+         RETURNING id",
+    )
+    .bind(list.id)
+    .bind(target_id)
+    .bind(tags)
+    .bind(notes)
+    .bind(metadata.as_ref().map(|m| m.name.clone()))
+    .bind(metadata.as_ref().map(|m| m.author_name.clone()))
+    .bind(metadata.as_ref().map(|m| m.thumbnail_url.clone()))
+    .fetch_one(pool)
+    .await
+    .map_err(|e| format!("Failed to add local favorite: {}", e))
+}
+
+#[tauri::command]
+pub async fn create_local_favorite_list(
+    app: AppHandle,
+    name: String,
+    favorite_type: FavoriteType,
+) -> Result<LocalFavoriteList, String> {
+    check_favorite_type(favorite_type)?;
+    if name.trim().is_empty() {
+        return Err("List name cannot be empty".to_string());
+    }
+
+    let pool = get_pool(&app).await?;
+    let id = insert_list(&pool, name.trim(), favorite_type).await?;
+
+    get_list(&pool, id).await
+}
+
+/// Deletes a list along with every favorite in it
+#[tauri::command]
+pub async fn delete_local_favorite_list(app: AppHandle, list_id: i64) -> Result<(), String> {
+    let pool = get_pool(&app).await?;
+
+    sqlx::query("DELETE FROM local_favorites WHERE list_id = $1")
+        .bind(list_id)
+        .execute(&pool)
+        .await
+        .map_err(|e| format!("Failed to delete local favorites: {}", e))?;
+
+    sqlx::query("DELETE FROM local_favorite_lists WHERE id = $1")
+        .bind(list_id)
+        .execute(&pool)
+        .await
+        .map_err(|e| format!("Failed to delete local favorite list: {}", e))?;
+
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn get_local_favorite_lists(
+    app: AppHandle,
+    favorite_type: Option<FavoriteType>,
+) -> Result<Vec<LocalFavoriteList>, String> {
+    let pool = get_pool(&app).await?;
+
+    let mut query = QueryBuilder::<Sqlite>::new(
+        "SELECT l.*, (SELECT COUNT(*) FROM local_favorites f WHERE f.list_id = l.id) AS count FROM local_favorite_lists l",
+    );
+    if let Some(favorite_type) = favorite_type {
+        query
+            .push(" WHERE l.type = ")
+            .push_bind(favorite_type.to_string());
+    }
+    query.push(" ORDER BY l.name");
+
+    query
+        .build_query_as::<LocalFavoriteList>()
+        .fetch_all(&pool)
+        .await
+        .map_err(|e| format!("Failed to get local favorite lists: {}", e))
+}
+
+/// Adds a world or avatar to a local list, its name, author and thumbnail are cached on insert
+#[tauri::command]
+pub async fn add_local_favorite(
+    app: AppHandle,
+    list_id: i64,
+    target_id: String,
+    tags: Option<Vec<String>>,
+    notes: Option<String>,
+) -> Result<LocalFavorite, String> {
+    let pool = get_pool(&app).await?;
+    let list = get_list(&pool, list_id).await?;
+
+    let id = upsert_favorite(
+        &app,
+        &pool,
+        &list,
+        &target_id,
+        &tags.unwrap_or_default(),
+        notes,
+    )
+    .await?;
+
+    get_favorite(&pool, id).await
+}
+
+/// Replaces the tags and notes of a local favorite
+#[tauri::command]
+pub async fn update_local_favorite(
+    app: AppHandle,
+    id: i64,
+    tags: Vec<String>,
+    notes: Option<String>,
+) -> Result<LocalFavorite, String> {
+    let pool = get_pool(&app).await?;
+    let tags =
+        serde_json::to_string(&tags).map_err(|e| format!("Failed to serialize tags: {}", e))?;
+
+    sqlx::query("UPDATE local_favorites SET tags = $1, notes = $2 WHERE id = $3")
+        .bind(tags)
+        .bind(notes)
+        .bind(id)
+        .execute(&pool)
+        .await
+        .map_err(|e| format!("Failed to update local favorite: {}", e))?;
+
+    get_favorite(&pool, id).await
+}
+
+#[tauri::command]
+pub async fn remove_local_favorite(app: AppHandle, id: i64) -> Result<(), String> {
+    let pool = get_pool(&app).await?;
+
+    sqlx::query("DELETE FROM local_favorites WHERE id = $1")
+        .bind(id)
+        .execute(&pool)
+        .await
+        .map_err(|e| format!("Failed to remove local favorite: {}", e))?;
+
+    Ok(())
+}
+
+/// Gets local favorites, optionally limited to one list and one tag
+#[tauri::command]
+pub async fn get_local_favorites(
+    app: AppHandle,
+    list_id: Option<i64>,
+    tag: Option<String>,
+) -> Result<Vec<LocalFavorite>, String> {
+    let pool = get_pool(&app).await?;
+
+    let mut query = QueryBuilder::<Sqlite>::new("SELECT * FROM local_favorites WHERE 1 = 1");
+    if let Some(list_id) = list_id {
+        query.push(" AND list_id = ").push_bind(list_id);
+    }
+    if let Some(tag) = tag {
+        query
+            .push(" AND EXISTS (SELECT 1 FROM json_each(tags) WHERE json_each.value = ")
+            .push_bind(tag)
+            .push(")");
+    }
+    query.push(" ORDER BY added_at DESC");
+
+    query
+        .build_query_as::<LocalFavorite>()
+        .fetch_all(&pool)
+        .await
+        .map_err(|e| format!("Failed to get local favorites: {}", e))
+}
+
+/// Re-resolves cached metadata that is missing or older than a week
+#[tauri::command]
+pub async fn refresh_local_favorite_metadata(app: AppHandle) -> Result<u32, String> {
+    let pool = get_pool(&app).await?;
+
+    let stale = sqlx::query_as::<_, LocalFavorite>(
+        "SELECT * FROM local_favorites WHERE cached_at IS NULL OR cached_at < Datetime('now', 'localtime', '-7 days')",
+    )
+    .fetch_all(&pool)
+    .await
+    .map_err(|e| format!("Failed to get local favorites: {}", e))?;
+
+    let mut refreshed = 0;
+    for favorite in stale {
+        let metadata = match resolve_metadata(&app, &favorite.target_id).await {
+            Ok(metadata) => metadata,
+            Err(e) => {
+                log::warn!(
+                    "Failed to resolve metadata for {}: {}",
+                    favorite.target_id,
+                    e
+                );
+                continue;
+            }
+        };
+
+        sqlx::query(
+            "UPDATE local_favorites SET name = $1, author_name = $2, thumbnail_url = $3, cached_at = Datetime('now', 'localtime') WHERE id = $4",
+        )
+        .bind(metadata.name)
+        .bind(metadata.author_name)
+        .bind(metadata.thumbnail_url)
+        .bind(favorite.id)
+        .execute(&pool)
+        .await
+        .map_err(|e| format!("Failed to update local favorite: {}", e))?;
+
+        refreshed += 1;
+    }
+
+    Ok(refreshed)
+}
+
+/// Copies remote favorites of a type into local lists, one list per remote favorite group
+#[tauri::command]
+pub async fn import_remote_favorites(
+    app: AppHandle,
+    favorite_type: FavoriteType,
+) -> Result<u32, String> {
+    check_favorite_type(favorite_type)?;
+
+    let pool = get_pool(&app).await?;
+    let groups = get_favorite_groups(&app).await?;
+    let favorites = get_all_favorites(&app, favorite_type).await?;
+
+    let mut imported = 0;
+    for group in groups
+        .iter()
+        .filter(|group| group.favorite_type == favorite_type)
+    {
+        let name = if group.display_name.is_empty() {
+            &group.name
+        } else {
+            &group.display_name
+        };
+
+        let list_id = match find_list_by_name(&pool, name, favorite_type).await? {
+            Some(id) => id,
+            None => insert_list(&pool, name, favorite_type).await?,
+        };
+        let list = get_list(&pool, list_id).await?;
+
+        for favorite in favorites
+            .iter()
+            .filter(|favorite| favorite.tags.contains(&group.name))
+        {
+            upsert_favorite(&app, &pool, &list, &favorite.favorite_id, &[], None).await?;
+            imported += 1;
+        }
+    }
+
+    Ok(imported)
+}
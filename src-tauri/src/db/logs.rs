@@ -0,0 +1,59 @@
+use tauri::AppHandle;
+
+use crate::db::get_pool;
+
+/// Adds a row to the `log` table, the same table the game log and `addManualLog` write to
+pub async fn add_log(
+    app: &AppHandle,
+    log_type: &str,
+    message: &str,
+    user: Option<&str>,
+    location: Option<&str>,
+) -> Result<(), String> {
+    let pool = get_pool(app).await?;
+
+    sqlx::query(
+        "INSERT INTO log (time, type, message, user, location) VALUES (Datetime('now', 'localtime'), $1, $2, $3, $4)",
+    )
+    .bind(log_type)
+    .bind(message)
+    .bind(user)
+    .bind(location)
+    .execute(&pool)
+    .await
+    .map_err(|e| format!("Failed to add log: {}", e))?;
+
+    Ok(())
+}
+
+/// Display names of join and leave rows logged without a user id, each name once
+pub async fn get_log_names_missing_user(app: &AppHandle) -> Result<Vec<String>, String> {
+    let pool = get_pool(app).await?;
+
+    sqlx::query_scalar::<_, String>(
+        "SELECT DISTINCT message FROM log WHERE type IN ('OnPlayerJoined', 'OnPlayerLeft') AND user IS NULL AND message IS NOT NULL",
+    )
+    .fetch_all(&pool)
+    .await
+    .map_err(|e| format!("Failed to get logs: {}", e))
+}
+
+/// Sets the user id of every join and leave row of a display name, returns the updated row count
+pub async fn set_log_user_by_name(
+    app: &AppHandle,
+    display_name: &str,
+    user: &str,
+) -> Result<u64, String> {
+    let pool = get_pool(app).await?;
+
+    let result = sqlx::query(
+        "UPDATE log SET user = $1 WHERE type IN ('OnPlayerJoined', 'OnPlayerLeft') AND user IS NULL AND message = $2",
+    )
+    .bind(user)
+    .bind(display_name)
+    .execute(&pool)
+    .await
+    .map_err(|e| format!("Failed to update log: {}", e))?;
+
+    Ok(result.rows_affected())
+}
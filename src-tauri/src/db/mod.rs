@@ -0,0 +1,27 @@
+use sqlx::{Pool, Sqlite};
+use tauri::{AppHandle, Manager};
+use tauri_plugin_sql::{DbInstances, DbPool};
+
+pub(crate) mod group_moderation;
+pub(crate) mod local_favorites;
+pub(crate) mod logs;
+pub(crate) mod notifications;
+pub(crate) mod player_moderations;
+pub(crate) mod user_notes;
+
+/// Connection string of the database managed by the sql plugin migrations
+pub const DB_URL: &str = "sqlite:spectre.db";
+
+/// Gets the sqlite pool opened by the sql plugin, the database is preloaded in `tauri.conf.json`
+pub async fn get_pool(app: &AppHandle) -> Result<Pool<Sqlite>, String> {
+    let instances = app.state::<DbInstances>();
+    let instances = instances.0.read().await;
+
+    match instances.get(DB_URL) {
+        Some(db) => {
+            let DbPool::Sqlite(pool) = db;
+            Ok(pool.clone())
+        }
+        None => Err("Database has not been loaded yet!".to_string()),
+    }
+}
@@ -0,0 +1,125 @@
+use serde::{Deserialize, Serialize};
+use sqlx::{QueryBuilder, Sqlite};
+use tauri::AppHandle;
+
+use crate::db::get_pool;
+
+/// A row of the `notifications` table
+#[derive(Serialize, Deserialize, Debug, Clone, sqlx::FromRow)]
+#[serde(rename_all = "camelCase")]
+pub struct NotificationHistoryEntry {
+    pub id: String,
+    #[serde(rename = "type")]
+    #[sqlx(rename = "type")]
+    pub notification_type: String,
+    pub sender: Option<String>,
+    pub details: Option<String>,
+    pub received_at: String,
+    pub seen_at: Option<String>,
+    pub responded_at: Option<String>,
+    pub action: Option<String>,
+}
+
+/// Filters for `get_notification_history`, every field is optional
+#[derive(Serialize, Deserialize, Debug, Default)]
+#[serde(rename_all = "camelCase", default)]
+pub struct NotificationHistoryFilter {
+    #[serde(rename = "type")]
+    pub notification_type: Option<String>,
+    pub sender: Option<String>,
+    pub unread_only: bool,
+    pub limit: Option<u32>,
+}
+
+/// Records a received notification, notifications that are already stored are left untouched
+#[tauri::command]
+pub async fn add_notification_history(
+    app: AppHandle,
+    id: String,
+    notification_type: String,
+    sender: Option<String>,
+    details: Option<serde_json::Value>,
+) -> Result<(), String> {
+    let pool = get_pool(&app).await?;
+
+    sqlx::query(
+        "INSERT OR IGNORE INTO notifications (id, type, sender, details, received_at) VALUES ($1, $2, $3, $4, Datetime('now', 'localtime'))",
+    )
+    .bind(id)
+    .bind(notification_type)
+    .bind(sender)
+    .bind(details.map(|d| d.to_string()))
+    .execute(&pool)
+    .await
+    .map_err(|e| format!("Failed to record notification: {}", e))?;
+
+    Ok(())
+}
+
+/// Marks a stored notification as seen
+#[tauri::command]
+pub async fn mark_notification_history_seen(app: AppHandle, id: String) -> Result<(), String> {
+    let pool = get_pool(&app).await?;
+
+    sqlx::query(
+        "UPDATE notifications SET seen_at = Datetime('now', 'localtime') WHERE id = $1 AND seen_at IS NULL",
+    )
+    .bind(id)
+    .execute(&pool)
+    .await
+    .map_err(|e| format!("Failed to mark notification as seen: {}", e))?;
+
+    Ok(())
+}
+
+/// Gets stored notifications, newest first
+#[tauri::command]
+pub async fn get_notification_history(
+    app: AppHandle,
+    filter: Option<NotificationHistoryFilter>,
+) -> Result<Vec<NotificationHistoryEntry>, String> {
+    let filter = filter.unwrap_or_default();
+    let pool = get_pool(&app).await?;
+
+    let mut query = QueryBuilder::<Sqlite>::new("SELECT * FROM notifications WHERE 1 = 1");
+
+    if let Some(notification_type) = filter.notification_type {
+        query.push(" AND type = ").push_bind(notification_type);
+    }
+    if let Some(sender) = filter.sender {
+        query.push(" AND sender = ").push_bind(sender);
+    }
+    if filter.unread_only {
+        query.push(" AND seen_at IS NULL AND responded_at IS NULL");
+    }
+
+    query
+        .push(" ORDER BY received_at DESC LIMIT ")
+        .push_bind(filter.limit.unwrap_or(1000));
+
+    query
+        .build_query_as::<NotificationHistoryEntry>()
+        .fetch_all(&pool)
+        .await
+        .map_err(|e| format!("Failed to get notification history: {}", e))
+}
+
+/// Stores the action taken on a notification, this also counts as seeing it
+pub async fn set_notification_action(
+    app: &AppHandle,
+    id: &str,
+    action: &str,
+) -> Result<(), String> {
+    let pool = get_pool(app).await?;
+
+    sqlx::query(
+        "UPDATE notifications SET action = $1, responded_at = Datetime('now', 'localtime'), seen_at = COALESCE(seen_at, Datetime('now', 'localtime')) WHERE id = $2",
+    )
+    .bind(action)
+    .bind(id)
+    .execute(&pool)
+    .await
+    .map_err(|e| format!("Failed to store notification action: {}", e))?;
+
+    Ok(())
+}
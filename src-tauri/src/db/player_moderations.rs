@@ -0,0 +1,126 @@
+use serde::{Deserialize, Serialize};
+use sqlx::{QueryBuilder, Sqlite};
+use tauri::AppHandle;
+
+use crate::db::get_pool;
+use crate::types::moderation::PlayerModeration;
+
+/// A player moderation mirrored into spectre.db, used to badge blocked or muted users
+#[derive(Serialize, Deserialize, Debug, Clone, sqlx::FromRow)]
+#[serde(rename_all = "camelCase")]
+pub struct LocalPlayerModeration {
+    pub id: String,
+    #[serde(rename = "type")]
+    #[sqlx(rename = "type")]
+    pub moderation_type: String,
+    pub target_user_id: String,
+    pub target_display_name: String,
+    pub created: Option<String>,
+    pub synced_at: String,
+}
+
+/// Replaces the local moderations with a full list from the API
+pub async fn replace_player_moderations(
+    app: &AppHandle,
+    moderations: &[PlayerModeration],
+) -> Result<(), String> {
+    let pool = get_pool(app).await?;
+    let mut tx = pool
+        .begin()
+        .await
+        .map_err(|e| format!("Failed to start transaction: {}", e))?;
+
+    sqlx::query("DELETE FROM player_moderations")
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| format!("Failed to clear player moderations: {}", e))?;
+
+    for moderation in moderations {
+        insert(&mut tx, moderation).await?;
+    }
+
+    tx.commit()
+        .await
+        .map_err(|e| format!("Failed to store player moderations: {}", e))
+}
+
+async fn insert(
+    tx: &mut sqlx::Transaction<'_, Sqlite>,
+    moderation: &PlayerModeration,
+) -> Result<(), String> {
+    sqlx::query(
+        "INSERT OR REPLACE INTO player_moderations (id, type, target_user_id, target_display_name, created, synced_at) VALUES ($1, $2, $3, $4, $5, Datetime('now', 'localtime'))",
+    )
+    .bind(&moderation.id)
+    .bind(moderation.moderation_type.to_string())
+    .bind(&moderation.target_user_id)
+    .bind(&moderation.target_display_name)
+    .bind(&moderation.created)
+    .execute(&mut **tx)
+    .await
+    .map_err(|e| format!("Failed to store player moderation: {}", e))?;
+
+    Ok(())
+}
+
+pub async fn add_player_moderation(
+    app: &AppHandle,
+    moderation: &PlayerModeration,
+) -> Result<(), String> {
+    let pool = get_pool(app).await?;
+    let mut tx = pool
+        .begin()
+        .await
+        .map_err(|e| format!("Failed to start transaction: {}", e))?;
+
+    insert(&mut tx, moderation).await?;
+
+    tx.commit()
+        .await
+        .map_err(|e| format!("Failed to store player moderation: {}", e))
+}
+
+pub async fn remove_player_moderation(
+    app: &AppHandle,
+    target_user_id: &str,
+    moderation_type: &str,
+) -> Result<(), String> {
+    let pool = get_pool(app).await?;
+
+    sqlx::query("DELETE FROM player_moderations WHERE target_user_id = $1 AND type = $2")
+        .bind(target_user_id)
+        .bind(moderation_type)
+        .execute(&pool)
+        .await
+        .map_err(|e| format!("Failed to remove player moderation: {}", e))?;
+
+    Ok(())
+}
+
+/// Gets the locally synced moderations, optionally only for the given users
+#[tauri::command]
+pub async fn get_local_player_moderations(
+    app: AppHandle,
+    user_ids: Option<Vec<String>>,
+) -> Result<Vec<LocalPlayerModeration>, String> {
+    let pool = get_pool(&app).await?;
+
+    let mut query = QueryBuilder::<Sqlite>::new("SELECT * FROM player_moderations");
+    if let Some(user_ids) = user_ids {
+        if user_ids.is_empty() {
+            return Ok(Vec::new());
+        }
+        query.push(" WHERE target_user_id IN (");
+        let mut separated = query.separated(", ");
+        for user_id in user_ids {
+            separated.push_bind(user_id);
+        }
+        separated.push_unseparated(")");
+    }
+
+    query
+        .build_query_as::<LocalPlayerModeration>()
+        .fetch_all(&pool)
+        .await
+        .map_err(|e| format!("Failed to get player moderations: {}", e))
+}
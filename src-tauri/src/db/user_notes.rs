@@ -0,0 +1,64 @@
+use serde::{Deserialize, Serialize};
+use tauri::AppHandle;
+
+use crate::db::get_pool;
+
+/// A user note cached in spectre.db
+#[derive(Serialize, Deserialize, Debug, Clone, sqlx::FromRow)]
+#[serde(rename_all = "camelCase")]
+pub struct CachedUserNote {
+    pub target_user_id: String,
+    pub note: String,
+    pub updated_at: String,
+}
+
+/// Caches a note, empty notes are removed from the cache
+pub async fn cache_user_note(
+    app: &AppHandle,
+    target_user_id: &str,
+    note: &str,
+) -> Result<(), String> {
+    let pool = get_pool(app).await?;
+
+    let query = if note.is_empty() {
+        sqlx::query("DELETE FROM user_notes WHERE target_user_id = $1").bind(target_user_id)
+    } else {
+        sqlx::query(
+            "INSERT INTO user_notes (target_user_id, note, updated_at) VALUES ($1, $2, Datetime('now', 'localtime'))
+             ON CONFLICT (target_user_id) DO UPDATE SET note = excluded.note, updated_at = excluded.updated_at",
+        )
+        .bind(target_user_id)
+        .bind(note)
+    };
+
+    query
+        .execute(&pool)
+        .await
+        .map_err(|e| format!("Failed to cache user note: {}", e))?;
+
+    Ok(())
+}
+
+pub async fn get_cached_user_note(
+    app: &AppHandle,
+    target_user_id: &str,
+) -> Result<Option<CachedUserNote>, String> {
+    let pool = get_pool(app).await?;
+
+    sqlx::query_as::<_, CachedUserNote>("SELECT * FROM user_notes WHERE target_user_id = $1")
+        .bind(target_user_id)
+        .fetch_optional(&pool)
+        .await
+        .map_err(|e| format!("Failed to get cached user note: {}", e))
+}
+
+/// Gets every cached note, used to show notes on friend cards without extra requests
+#[tauri::command]
+pub async fn get_cached_user_notes(app: AppHandle) -> Result<Vec<CachedUserNote>, String> {
+    let pool = get_pool(&app).await?;
+
+    sqlx::query_as::<_, CachedUserNote>("SELECT * FROM user_notes")
+        .fetch_all(&pool)
+        .await
+        .map_err(|e| format!("Failed to get cached user notes: {}", e))
+}
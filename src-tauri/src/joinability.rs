@@ -0,0 +1,514 @@
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use std::sync::Mutex;
+use std::time::Duration;
+use tauri::{AppHandle, Manager};
+
+use crate::types::group::Group;
+use crate::types::instance::{Instance, InstanceAccess};
+use crate::types::location::{Location, UserLocation};
+use crate::types::user::CurrentUser;
+use crate::web::vrc_group::get_cached_group;
+use crate::web::vrc_instance::get_cached_instance;
+use crate::web::vrc_user::get_current_user;
+
+const INSTANCE_TTL: Duration = Duration::from_secs(60);
+const GROUP_TTL: Duration = Duration::from_secs(300);
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub enum JoinReason {
+    Offline,
+    Private,
+    Traveling,
+    InvalidLocation,
+    Closed,
+    Public,
+    FriendsPlus,
+    OwnInstance,
+    OwnerIsFriend,
+    OwnerNotFriend,
+    InviteOnly,
+    CanRequestInvite,
+    GroupPublic,
+    GroupPlus,
+    GroupMember,
+    NotGroupMember,
+    RoleRestricted,
+    AgeGated,
+    Full,
+    Queue,
+}
+
+/// Whether the current user can join a location, and why
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct Joinability {
+    pub joinable: bool,
+    pub reason: JoinReason,
+    /// The location can't be joined directly but an invite can be requested
+    pub can_request_invite: bool,
+}
+
+impl Joinability {
+    fn yes(reason: JoinReason) -> Self {
+        Joinability {
+            joinable: true,
+            reason,
+            can_request_invite: false,
+        }
+    }
+
+    fn no(reason: JoinReason) -> Self {
+        Joinability {
+            joinable: false,
+            reason,
+            can_request_invite: false,
+        }
+    }
+}
+
+/// Joinability of a friend, `location` is the location it was decided for
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct FriendJoinability {
+    pub user_id: String,
+    pub location: String,
+    pub joinability: Joinability,
+}
+
+/// The parts of the current user joinability needs
+#[derive(Clone)]
+struct JoinUser {
+    user_id: String,
+    friend_ids: HashSet<String>,
+    age_verified: bool,
+}
+
+impl From<CurrentUser> for JoinUser {
+    fn from(user: CurrentUser) -> Self {
+        JoinUser {
+            age_verified: user.age_verification_status == "18+",
+            friend_ids: user.friends.into_iter().collect(),
+            user_id: user.id,
+        }
+    }
+}
+
+/// The current user kept between evaluations, so friend events don't fetch it every time.
+/// Set on login and kept up to date by `friend-add` and `friend-delete` events
+#[derive(Default)]
+pub struct JoinState(Mutex<Option<JoinUser>>);
+
+impl JoinState {
+    /// Replaces the current user, `None` loads it again on the next evaluation
+    pub fn set_user(&self, user: Option<CurrentUser>) {
+        *self.0.lock().unwrap() = user.map(JoinUser::from);
+    }
+}
+
+/// What is known about the current user when deciding joinability
+pub struct JoinContext {
+    pub user_id: String,
+    pub friend_ids: HashSet<String>,
+    pub age_verified: bool,
+    /// Groups of group instances being evaluated, `None` when the group couldn't be loaded
+    pub groups: HashMap<String, Option<Group>>,
+}
+
+impl JoinContext {
+    pub async fn load(app: &AppHandle) -> Result<Self, String> {
+        let cached = app.state::<JoinState>().0.lock().unwrap().clone();
+        let user = match cached {
+            Some(user) => user,
+            None => {
+                let user = JoinUser::from(get_current_user(app).await?);
+                *app.state::<JoinState>().0.lock().unwrap() = Some(user.clone());
+                user
+            }
+        };
+
+        Ok(JoinContext {
+            user_id: user.user_id,
+            friend_ids: user.friend_ids,
+            age_verified: user.age_verified,
+            groups: HashMap::new(),
+        })
+    }
+
+    /// Loads the group of a members only group location once, so a snapshot full of the same
+    /// group stays cheap
+    pub async fn load_group(&mut self, app: &AppHandle, location: &Location) {
+        let Some(group_id) = location
+            .group_id
+            .as_ref()
+            .filter(|_| location.access == InstanceAccess::Group)
+        else {
+            return;
+        };
+        if self.groups.contains_key(group_id) {
+            return;
+        }
+        let group = match get_cached_group(app, group_id, GROUP_TTL).await {
+            Ok(group) => Some(group),
+            Err(e) => {
+                log::warn!("{}", e);
+                None
+            }
+        };
+        self.groups.insert(group_id.clone(), group);
+    }
+}
+
+/// Decides whether the current user can join a location, `instance` adds capacity, queue,
+/// closed state and role restrictions when it is known
+pub fn evaluate(location: &str, instance: Option<&Instance>, ctx: &JoinContext) -> Joinability {
+    let location = match UserLocation::parse(location) {
+        Ok(UserLocation::Instance(location)) => location,
+        Ok(UserLocation::Offline) => return Joinability::no(JoinReason::Offline),
+        Ok(UserLocation::Private) => return Joinability::no(JoinReason::Private),
+        Ok(UserLocation::Traveling) => return Joinability::no(JoinReason::Traveling),
+        Err(_) => return Joinability::no(JoinReason::InvalidLocation),
+    };
+
+    if instance.is_some_and(|instance| instance.closed_at.is_some()) {
+        return Joinability::no(JoinReason::Closed);
+    }
+
+    let owner_id = location.owner_id.as_deref().unwrap_or_default();
+    let is_owner = owner_id == ctx.user_id;
+    let group = location
+        .group_id
+        .as_ref()
+        .and_then(|group_id| ctx.groups.get(group_id))
+        .and_then(Option::as_ref);
+
+    let access = match location.access {
+        InstanceAccess::Public => Joinability::yes(JoinReason::Public),
+        // The friend is in the instance, which is enough for friends+
+        InstanceAccess::FriendsPlus => Joinability::yes(JoinReason::FriendsPlus),
+        // Friends of anyone in a group+ instance can join, like friends+
+        InstanceAccess::GroupPlus => Joinability::yes(JoinReason::GroupPlus),
+        _ if is_owner => Joinability::yes(JoinReason::OwnInstance),
+        InstanceAccess::Friends if ctx.friend_ids.contains(owner_id) => {
+            Joinability::yes(JoinReason::OwnerIsFriend)
+        }
+        InstanceAccess::Friends => Joinability::no(JoinReason::OwnerNotFriend),
+        InstanceAccess::Invite => Joinability::no(JoinReason::InviteOnly),
+        InstanceAccess::InvitePlus => Joinability {
+            joinable: false,
+            reason: JoinReason::CanRequestInvite,
+            can_request_invite: true,
+        },
+        InstanceAccess::GroupPublic => Joinability::yes(JoinReason::GroupPublic),
+        InstanceAccess::Group => match group {
+            Some(group) if group.is_member() => group_role_access(group, instance),
+            _ => Joinability::no(JoinReason::NotGroupMember),
+        },
+    };
+    if !access.joinable {
+        return Joinability {
+            can_request_invite: access.can_request_invite || location.can_request_invite,
+            ..access
+        };
+    }
+
+    if location.age_gate && !ctx.age_verified {
+        return Joinability::no(JoinReason::AgeGated);
+    }
+
+    match instance {
+        Some(instance) if instance.capacity > 0 && instance.n_users >= instance.capacity => {
+            if instance.queue_enabled {
+                Joinability::yes(JoinReason::Queue)
+            } else {
+                Joinability::no(JoinReason::Full)
+            }
+        }
+        _ => access,
+    }
+}
+
+/// Members of role restricted instances need one of the allowed roles
+fn group_role_access(group: &Group, instance: Option<&Instance>) -> Joinability {
+    let Some(instance) = instance.filter(|instance| instance.role_restricted) else {
+        return Joinability::yes(JoinReason::GroupMember);
+    };
+
+    let role_ids = group
+        .my_member
+        .as_ref()
+        .map(|member| member.role_ids.as_slice())
+        .unwrap_or_default();
+    if role_ids.iter().any(|role| instance.role_ids.contains(role)) {
+        Joinability::yes(JoinReason::GroupMember)
+    } else {
+        Joinability::no(JoinReason::RoleRestricted)
+    }
+}
+
+/// Evaluates a location with everything the backend can find out about it, the group of a group
+/// location has to be loaded into `ctx` first
+pub async fn evaluate_loaded(app: &AppHandle, location: &str, ctx: &JoinContext) -> Joinability {
+    if Location::parse(location).is_err() {
+        return evaluate(location, None, ctx);
+    }
+
+    // The instance can only rule a location out, so it isn't fetched when the access type
+    // already does
+    let access = evaluate(location, None, ctx);
+    if !access.joinable {
+        return access;
+    }
+
+    let instance = match get_cached_instance(app, location, INSTANCE_TTL).await {
+        Ok(instance) => Some(instance),
+        Err(e) => {
+            log::warn!("{}", e);
+            None
+        }
+    };
+
+    evaluate(location, instance.as_ref(), ctx)
+}
+
+/// Loads what a location needs into `ctx` and evaluates it
+pub async fn evaluate_location(
+    app: &AppHandle,
+    location: &str,
+    ctx: &mut JoinContext,
+) -> Joinability {
+    if let Ok(parsed) = Location::parse(location) {
+        ctx.load_group(app, &parsed).await;
+    }
+    evaluate_loaded(app, location, ctx).await
+}
+
+/// Keeps the friends of the join state in sync with `friend-add` and `friend-delete` events
+#[tauri::command]
+pub fn update_join_friend(app: AppHandle, user_id: String, friend: bool) {
+    if let Some(user) = app.state::<JoinState>().0.lock().unwrap().as_mut() {
+        if friend {
+            user.friend_ids.insert(user_id);
+        } else {
+            user.friend_ids.remove(&user_id);
+        }
+    }
+}
+
+#[tauri::command]
+pub async fn get_vrc_joinability(app: AppHandle, location: String) -> Result<Joinability, String> {
+    let mut ctx = JoinContext::load(&app).await?;
+    Ok(evaluate_location(&app, &location, &mut ctx).await)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    const ME: &str = "usr_me";
+    const FRIEND: &str = "usr_friend";
+
+    fn context() -> JoinContext {
+        let member: Group = serde_json::from_value(json!({
+            "id": "grp_member",
+            "name": "Member",
+            "myMember": {
+                "id": "gmem_1",
+                "groupId": "grp_member",
+                "userId": ME,
+                "roleIds": ["grol_allowed"],
+                "membershipStatus": "member"
+            }
+        }))
+        .unwrap();
+        let outsider: Group = serde_json::from_value(json!({
+            "id": "grp_outsider",
+            "name": "Outsider",
+            "membershipStatus": "inactive"
+        }))
+        .unwrap();
+
+        JoinContext {
+            user_id: ME.to_string(),
+            friend_ids: HashSet::from([FRIEND.to_string()]),
+            age_verified: false,
+            groups: HashMap::from([
+                ("grp_member".to_string(), Some(member)),
+                ("grp_outsider".to_string(), Some(outsider)),
+                ("grp_unloaded".to_string(), None),
+            ]),
+        }
+    }
+
+    fn instance(extra: serde_json::Value) -> Instance {
+        let mut instance = json!({
+            "id": "wrld_a:1",
+            "location": "wrld_a:1",
+            "instanceId": "1",
+            "worldId": "wrld_a",
+            "n_users": 4,
+            "capacity": 16
+        });
+        if let (Some(instance), Some(extra)) = (instance.as_object_mut(), extra.as_object()) {
+            instance.extend(extra.clone());
+        }
+        serde_json::from_value(instance).unwrap()
+    }
+
+    fn decide(location: &str, instance: Option<&Instance>) -> (bool, JoinReason) {
+        let joinability = evaluate(location, instance, &context());
+        (joinability.joinable, joinability.reason)
+    }
+
+    #[test]
+    fn special_locations() {
+        assert_eq!(decide("offline", None), (false, JoinReason::Offline));
+        assert_eq!(decide("", None), (false, JoinReason::Offline));
+        assert_eq!(decide("private", None), (false, JoinReason::Private));
+        assert_eq!(decide("traveling", None), (false, JoinReason::Traveling));
+        assert_eq!(decide("wrld_a", None), (false, JoinReason::InvalidLocation));
+    }
+
+    #[test]
+    fn user_access_types() {
+        assert_eq!(decide("wrld_a:1", None), (true, JoinReason::Public));
+        assert_eq!(
+            decide("wrld_a:1~hidden(usr_stranger)", None),
+            (true, JoinReason::FriendsPlus)
+        );
+        assert_eq!(
+            decide("wrld_a:1~friends(usr_friend)", None),
+            (true, JoinReason::OwnerIsFriend)
+        );
+        assert_eq!(
+            decide("wrld_a:1~friends(usr_stranger)", None),
+            (false, JoinReason::OwnerNotFriend)
+        );
+        assert_eq!(
+            decide("wrld_a:1~private(usr_me)", None),
+            (true, JoinReason::OwnInstance)
+        );
+        assert_eq!(
+            decide("wrld_a:1~private(usr_friend)", None),
+            (false, JoinReason::InviteOnly)
+        );
+
+        let invite_plus = evaluate(
+            "wrld_a:1~private(usr_friend)~canRequestInvite",
+            None,
+            &context(),
+        );
+        assert!(!invite_plus.joinable);
+        assert_eq!(invite_plus.reason, JoinReason::CanRequestInvite);
+        assert!(invite_plus.can_request_invite);
+
+        assert!(!evaluate("wrld_a:1~friends(usr_stranger)", None, &context()).can_request_invite);
+    }
+
+    #[test]
+    fn group_access_types() {
+        assert_eq!(
+            decide("wrld_a:1~group(grp_outsider)~groupAccessType(public)", None),
+            (true, JoinReason::GroupPublic)
+        );
+        assert_eq!(
+            decide("wrld_a:1~group(grp_outsider)~groupAccessType(plus)", None),
+            (true, JoinReason::GroupPlus)
+        );
+        assert_eq!(
+            decide("wrld_a:1~group(grp_member)~groupAccessType(members)", None),
+            (true, JoinReason::GroupMember)
+        );
+        assert_eq!(
+            decide("wrld_a:1~group(grp_member)", None),
+            (true, JoinReason::GroupMember)
+        );
+        assert_eq!(
+            decide(
+                "wrld_a:1~group(grp_outsider)~groupAccessType(members)",
+                None
+            ),
+            (false, JoinReason::NotGroupMember)
+        );
+        assert_eq!(
+            decide(
+                "wrld_a:1~group(grp_unloaded)~groupAccessType(members)",
+                None
+            ),
+            (false, JoinReason::NotGroupMember)
+        );
+    }
+
+    #[test]
+    fn role_restrictions() {
+        let location = "wrld_a:1~group(grp_member)~groupAccessType(members)";
+
+        let allowed = instance(json!({ "roleRestricted": true, "roleIds": ["grol_allowed"] }));
+        assert_eq!(
+            decide(location, Some(&allowed)),
+            (true, JoinReason::GroupMember)
+        );
+
+        let other = instance(json!({ "roleRestricted": true, "roleIds": ["grol_other"] }));
+        assert_eq!(
+            decide(location, Some(&other)),
+            (false, JoinReason::RoleRestricted)
+        );
+
+        let unrestricted = instance(json!({ "roleIds": ["grol_other"] }));
+        assert_eq!(
+            decide(location, Some(&unrestricted)),
+            (true, JoinReason::GroupMember)
+        );
+    }
+
+    #[test]
+    fn age_gate() {
+        let location = "wrld_a:1~hidden(usr_friend)~ageGate";
+        assert_eq!(decide(location, None), (false, JoinReason::AgeGated));
+
+        let ctx = JoinContext {
+            age_verified: true,
+            ..context()
+        };
+        assert!(evaluate(location, None, &ctx).joinable);
+    }
+
+    #[test]
+    fn full_and_queued_instances() {
+        let location = "wrld_a:1~hidden(usr_friend)";
+
+        let room = instance(json!({ "n_users": 15 }));
+        assert_eq!(
+            decide(location, Some(&room)),
+            (true, JoinReason::FriendsPlus)
+        );
+
+        let full = instance(json!({ "n_users": 16 }));
+        assert_eq!(decide(location, Some(&full)), (false, JoinReason::Full));
+
+        let queue = instance(json!({ "n_users": 20, "queueEnabled": true, "queueSize": 3 }));
+        assert_eq!(decide(location, Some(&queue)), (true, JoinReason::Queue));
+
+        // Invite only stays the reason even when the instance is full
+        assert_eq!(
+            decide("wrld_a:1~private(usr_friend)", Some(&full)),
+            (false, JoinReason::InviteOnly)
+        );
+    }
+
+    #[test]
+    fn closed_instances() {
+        let closed = instance(json!({ "closedAt": "2024-01-01T00:00:00.000Z" }));
+        assert_eq!(
+            decide("wrld_a:1", Some(&closed)),
+            (false, JoinReason::Closed)
+        );
+        assert_eq!(
+            decide("wrld_a:1~private(usr_me)", Some(&closed)),
+            (false, JoinReason::Closed)
+        );
+    }
+}
@@ -1,7 +1,17 @@
 use tauri::Manager;
 use tauri_plugin_sql::{Migration, MigrationKind};
 
+mod alerts;
+mod confirmation;
+mod db;
+mod joinability;
+mod queue;
+mod rules;
+mod settings;
+mod status;
 mod types;
+mod watcher;
+mod watchlist;
 mod web;
 
 #[cfg_attr(mobile, tauri::mobile_entry_point)]
@@ -13,10 +23,50 @@ pub fn run() {
             description: "create_initial_tables",
             sql: "CREATE TABLE log (id INTEGER PRIMARY KEY, time DATETIME, type TEXT, message TEXT, user TEXT, location TEXT);",
             kind: MigrationKind::Up,
-        }
+        },
+        Migration {
+            version: 2,
+            description: "create_notifications_table",
+            sql: "CREATE TABLE notifications (id TEXT PRIMARY KEY, type TEXT NOT NULL, sender TEXT, details TEXT, received_at DATETIME NOT NULL, seen_at DATETIME, responded_at DATETIME, action TEXT);
+                  CREATE INDEX idx_notifications_received_at ON notifications (received_at);",
+            kind: MigrationKind::Up,
+        },
+        Migration {
+            version: 3,
+            description: "create_local_favorites_tables",
+            sql: "CREATE TABLE local_favorite_lists (id INTEGER PRIMARY KEY, name TEXT NOT NULL, type TEXT NOT NULL, created_at DATETIME NOT NULL, UNIQUE (name, type));
+                  CREATE TABLE local_favorites (id INTEGER PRIMARY KEY, list_id INTEGER NOT NULL REFERENCES local_favorite_lists (id), target_id TEXT NOT NULL, tags TEXT NOT NULL DEFAULT '[]', notes TEXT, name TEXT, author_name TEXT, thumbnail_url TEXT, cached_at DATETIME, added_at DATETIME NOT NULL, UNIQUE (list_id, target_id));",
+            kind: MigrationKind::Up,
+        },
+        Migration {
+            version: 4,
+            description: "create_user_notes_table",
+            sql: "CREATE TABLE user_notes (target_user_id TEXT PRIMARY KEY, note TEXT NOT NULL, updated_at DATETIME NOT NULL);",
+            kind: MigrationKind::Up,
+        },
+        Migration {
+            version: 5,
+            description: "create_player_moderations_table",
+            sql: "CREATE TABLE player_moderations (id TEXT PRIMARY KEY, type TEXT NOT NULL, target_user_id TEXT NOT NULL, target_display_name TEXT NOT NULL, created DATETIME, synced_at DATETIME NOT NULL);
+                  CREATE INDEX idx_player_moderations_target ON player_moderations (target_user_id);",
+            kind: MigrationKind::Up,
+        },
+        Migration {
+            version: 6,
+            description: "create_group_moderation_log_table",
+            sql: "CREATE TABLE group_moderation_log (id INTEGER PRIMARY KEY AUTOINCREMENT, group_id TEXT NOT NULL, action TEXT NOT NULL, target_user_id TEXT, details TEXT, created DATETIME NOT NULL);
+                  CREATE INDEX idx_group_moderation_log_group ON group_moderation_log (group_id);",
+            kind: MigrationKind::Up,
+        },
     ];
 
     let _builder = tauri::Builder::default()
+        .manage(confirmation::ConfirmationTokens::default())
+        .manage(web::cache::ResponseCache::default())
+        .manage(web::rate_limit::RateLimiter::default())
+        .manage(queue::InstanceQueues::default())
+        .manage(watchlist::WatchlistState::default())
+        .manage(joinability::JoinState::default())
         .plugin(tauri_plugin_log::Builder::new().build())
         .setup(|app| {
             #[cfg(desktop)]
@@ -32,11 +82,13 @@ pub fn run() {
             let _ = app
                 .handle()
                 .plugin(tauri_plugin_updater::Builder::new().build());
+            status::scheduler::start(app.handle().clone());
+            watcher::poller::start(app.handle().clone());
             Ok(())
         })
         .plugin(
             tauri_plugin_sql::Builder::new()
-                .add_migrations("sqlite:spectre.db", migrations)
+                .add_migrations(db::DB_URL, migrations)
                 .build(),
         )
         .plugin(tauri_plugin_dialog::init())
@@ -65,15 +117,131 @@ pub fn run() {
             // VRC Web Functions
             web::vrc_request::vrc_get_request,
             web::vrc_friends::get_vrc_friends,
+            web::vrc_friends::post_vrc_friend_request,
+            web::vrc_friends::delete_vrc_friend_request,
+            web::vrc_friends::get_vrc_friend_status,
+            web::vrc_friends::delete_vrc_friend,
             web::vrc_favorites::get_vrc_favorites,
+            web::vrc_favorites::get_vrc_favorite_list,
+            web::vrc_favorites::get_vrc_favorite_groups,
+            web::vrc_favorites::post_vrc_favorite,
+            web::vrc_favorites::put_vrc_move_favorite,
+            web::vrc_favorites::delete_vrc_favorite,
             web::vrc_user::get_vrc_user,
+            web::vrc_user::put_vrc_update_user,
+            web::vrc_user_search::search_vrc_users,
+            web::vrc_user_search::resolve_vrc_user_by_name,
+            web::vrc_user_search::resolve_log_user_ids,
+            web::vrc_user_notes::get_vrc_user_notes,
+            web::vrc_user_notes::get_vrc_user_note,
+            web::vrc_user_notes::post_vrc_user_note,
+            web::vrc_player_moderation::get_vrc_player_moderations,
+            web::vrc_player_moderation::post_vrc_player_moderation,
+            web::vrc_player_moderation::put_vrc_remove_player_moderation,
+            web::vrc_player_moderation::sync_vrc_player_moderations,
             web::vrc_instance::get_vrc_instance,
+            web::vrc_instance::post_vrc_create_instance,
+            web::vrc_instance::delete_vrc_close_instance,
+            web::vrc_instance::get_vrc_instance_players,
+            web::vrc_instance::post_vrc_group_ban_from_instance,
+            joinability::get_vrc_joinability,
+            joinability::update_join_friend,
+            types::location::parse_location,
+            types::location::build_location,
             web::vrc_group::get_vrc_group,
+            web::vrc_group::get_vrc_group_members,
+            web::vrc_group::get_vrc_group_roles,
+            web::vrc_group::get_vrc_group_instances,
+            web::vrc_group::get_vrc_group_posts,
+            web::vrc_group::get_vrc_group_announcement,
+            web::vrc_group::get_vrc_group_gallery,
+            web::vrc_group::get_vrc_group_events,
+            web::vrc_group_membership::post_vrc_join_group,
+            web::vrc_group_membership::post_vrc_request_join_group,
+            web::vrc_group_membership::delete_vrc_group_join_request,
+            web::vrc_group_membership::post_vrc_leave_group,
+            web::vrc_group_membership::put_vrc_represent_group,
+            web::vrc_group_membership::put_vrc_update_group_membership,
+            web::vrc_group_membership::post_vrc_accept_group_invite,
+            web::vrc_group_membership::delete_vrc_decline_group_invite,
+            web::vrc_group_moderation::get_vrc_group_join_requests,
+            web::vrc_group_moderation::put_vrc_respond_group_join_request,
+            web::vrc_group_moderation::get_vrc_group_audit_logs,
+            web::vrc_group_moderation::post_vrc_group_ban,
+            web::vrc_group_moderation::delete_vrc_group_ban,
+            web::vrc_group_moderation::delete_vrc_group_member,
+            web::vrc_group_moderation::put_vrc_group_member_role,
+            web::vrc_group_moderation::delete_vrc_group_member_role,
             web::vrc_avatar_list::get_vrc_avatar_list,
             web::vrc_current_avatar::get_vrc_current_avatar,
             web::vrc_notifications::get_vrc_notifications,
             web::vrc_notifications::put_vrc_see_notification,
+            web::vrc_notifications::put_vrc_accept_notification,
+            web::vrc_notifications::put_vrc_hide_notification,
+            web::vrc_notifications::put_vrc_clear_notifications,
+            web::vrc_notifications::post_vrc_invite_response,
+            web::vrc_notifications::get_vrc_notifications_v2,
+            web::vrc_notifications::post_vrc_notification_v2_response,
+            web::vrc_notifications::put_vrc_see_notification_v2,
             web::vrc_world::get_vrc_world,
+            web::vrc_world::search_vrc_worlds,
+            web::vrc_world::get_vrc_active_worlds,
+            web::vrc_world::get_vrc_recent_worlds,
+            web::vrc_world::get_vrc_favorite_worlds,
+            web::vrc_avatar::get_vrc_avatar,
+            web::vrc_avatar::put_vrc_select_avatar,
+            web::vrc_avatar::put_vrc_update_avatar,
+            web::vrc_avatar::search_vrc_own_avatars,
+            web::vrc_invite::post_vrc_invite,
+            web::vrc_invite::post_vrc_request_invite,
+            web::vrc_invite::post_vrc_invite_myself,
+            // Database functions
+            db::notifications::add_notification_history,
+            db::notifications::mark_notification_history_seen,
+            db::notifications::get_notification_history,
+            db::local_favorites::create_local_favorite_list,
+            db::local_favorites::delete_local_favorite_list,
+            db::local_favorites::get_local_favorite_lists,
+            db::local_favorites::add_local_favorite,
+            db::local_favorites::update_local_favorite,
+            db::local_favorites::remove_local_favorite,
+            db::local_favorites::get_local_favorites,
+            db::local_favorites::refresh_local_favorite_metadata,
+            db::local_favorites::import_remote_favorites,
+            db::user_notes::get_cached_user_notes,
+            db::player_moderations::get_local_player_moderations,
+            db::group_moderation::get_group_moderation_history,
+            // Notification rules
+            rules::get_notification_rules,
+            rules::add_notification_rule,
+            rules::update_notification_rule,
+            rules::delete_notification_rule,
+            rules::evaluate_notification_rules,
+            rules::dry_run_notification_rule,
+            // Confirmation of destructive actions
+            confirmation::request_confirmation_token,
+            // Status presets
+            status::get_status_presets,
+            status::save_status_preset,
+            status::delete_status_preset,
+            status::apply_status_preset,
+            status::get_status_schedule,
+            status::set_status_schedule,
+            // Instance queues
+            queue::handle_instance_queue_event,
+            queue::get_instance_queues,
+            queue::get_queue_notification_settings,
+            queue::set_queue_notification_settings,
+            // Capacity watcher
+            watcher::get_capacity_watches,
+            watcher::add_capacity_watch,
+            watcher::remove_capacity_watch,
+            // Friend watchlist
+            watchlist::get_friend_watchlist,
+            watchlist::add_watchlist_entry,
+            watchlist::update_watchlist_entry,
+            watchlist::delete_watchlist_entry,
+            watchlist::evaluate_friend_watchlist,
             // Cookies handling
             web::cookies::load_login_cookies,
             web::cookies::save_login_cookies,
@@ -0,0 +1,195 @@
+use chrono::Local;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::Mutex;
+use tauri::{AppHandle, Emitter, Manager};
+
+use crate::settings::{load_setting, save_setting};
+
+// Full instances put joining players into a queue, the pipeline reports every step of it and the
+// frontend forwards those events here so the queue state survives page changes
+const SETTINGS_KEY: &str = "queueNotifications";
+const QUEUE_EVENT: &str = "instance-queue-updated";
+
+#[derive(Default)]
+pub struct InstanceQueues(Mutex<HashMap<String, QueueState>>);
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub enum QueueStatus {
+    Waiting,
+    Ready,
+    Left,
+}
+
+/// The current user's place in an instance queue
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct QueueState {
+    pub location: String,
+    pub status: QueueStatus,
+    pub position: Option<u32>,
+    pub queue_size: Option<u32>,
+    /// When a ready spot is given up if the user doesn't join
+    pub expiry: Option<String>,
+    pub joined_at: String,
+    pub updated_at: String,
+}
+
+/// The content of the `instance-queue-*` pipeline events
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct QueueEvent {
+    pub instance_location: String,
+    #[serde(default)]
+    pub position: Option<u32>,
+    #[serde(default)]
+    pub queue_size: Option<u32>,
+    #[serde(default)]
+    pub expiry: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct QueueNotificationSettings {
+    pub on_ready: bool,
+    pub on_position_change: bool,
+    /// Only notify about position changes at or below this position
+    #[serde(default)]
+    pub position_threshold: Option<u32>,
+}
+
+impl Default for QueueNotificationSettings {
+    fn default() -> Self {
+        QueueNotificationSettings {
+            on_ready: true,
+            on_position_change: false,
+            position_threshold: None,
+        }
+    }
+}
+
+/// A notification the frontend should deliver through the rules engine
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct QueueAlert {
+    pub title: String,
+    pub message: String,
+}
+
+fn load_settings(app: &AppHandle) -> Result<QueueNotificationSettings, String> {
+    Ok(load_setting(app, SETTINGS_KEY)?.unwrap_or_default())
+}
+
+fn alert_for(
+    settings: &QueueNotificationSettings,
+    previous: Option<&QueueState>,
+    state: &QueueState,
+) -> Option<QueueAlert> {
+    match state.status {
+        QueueStatus::Ready if settings.on_ready => Some(QueueAlert {
+            title: "Your instance queue is ready!".to_string(),
+            message: format!("You can now join {}", state.location),
+        }),
+        QueueStatus::Waiting if settings.on_position_change => {
+            let position = state.position?;
+            if previous.and_then(|p| p.position) == Some(position) {
+                return None;
+            }
+            if settings
+                .position_threshold
+                .is_some_and(|max| position > max)
+            {
+                return None;
+            }
+            Some(QueueAlert {
+                title: format!("You are number {} in the instance queue", position),
+                message: match state.queue_size {
+                    Some(size) => format!("{} of {} in {}", position, size, state.location),
+                    None => format!("{} in {}", position, state.location),
+                },
+            })
+        }
+        _ => None,
+    }
+}
+
+/// Updates the queue state from a pipeline event and emits `instance-queue-updated`,
+/// returns a notification when the settings ask for one
+#[tauri::command]
+pub fn handle_instance_queue_event(
+    app: AppHandle,
+    event_type: String,
+    event: QueueEvent,
+) -> Result<Option<QueueAlert>, String> {
+    let now = Local::now().to_rfc3339();
+    let queues = app.state::<InstanceQueues>();
+
+    let (previous, state) = {
+        let mut queues = queues.0.lock().unwrap();
+        let previous = queues.get(&event.instance_location).cloned();
+
+        let mut state = previous.clone().unwrap_or_else(|| QueueState {
+            location: event.instance_location.clone(),
+            status: QueueStatus::Waiting,
+            position: None,
+            queue_size: None,
+            expiry: None,
+            joined_at: now.clone(),
+            updated_at: now.clone(),
+        });
+        state.updated_at = now;
+
+        match event_type.as_str() {
+            "instance-queue-joined" | "instance-queue-position" => {
+                state.status = QueueStatus::Waiting;
+                state.position = event.position.or(state.position);
+                state.queue_size = event.queue_size.or(state.queue_size);
+            }
+            "instance-queue-ready" => {
+                state.status = QueueStatus::Ready;
+                state.position = None;
+                state.expiry = event.expiry;
+            }
+            "instance-queue-left" => state.status = QueueStatus::Left,
+            _ => return Err(format!("{} is not an instance queue event", event_type)),
+        }
+
+        if state.status == QueueStatus::Left {
+            queues.remove(&state.location);
+        } else {
+            queues.insert(state.location.clone(), state.clone());
+        }
+        (previous, state)
+    };
+
+    if let Err(e) = app.emit(QUEUE_EVENT, &state) {
+        log::warn!("Failed to emit queue update: {}", e);
+    }
+
+    let settings = load_settings(&app)?;
+    Ok(alert_for(&settings, previous.as_ref(), &state))
+}
+
+/// Gets the queues the current user is waiting in
+#[tauri::command]
+pub fn get_instance_queues(app: AppHandle) -> Vec<QueueState> {
+    let queues = app.state::<InstanceQueues>();
+    let queues = queues.0.lock().unwrap();
+    queues.values().cloned().collect()
+}
+
+#[tauri::command]
+pub fn get_queue_notification_settings(
+    app: AppHandle,
+) -> Result<QueueNotificationSettings, String> {
+    load_settings(&app)
+}
+
+#[tauri::command]
+pub fn set_queue_notification_settings(
+    app: AppHandle,
+    settings: QueueNotificationSettings,
+) -> Result<(), String> {
+    save_setting(&app, SETTINGS_KEY, &settings)
+}
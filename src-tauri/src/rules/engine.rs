@@ -0,0 +1,289 @@
+use chrono::NaiveTime;
+use serde::{Deserialize, Serialize};
+
+/// What to do with an event once a rule matches it
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub enum RuleAction {
+    /// Desktop and overlay notification, this is what happens when no rule matches
+    Notify,
+    Desktop,
+    Overlay,
+    LogOnly,
+    Suppress,
+}
+
+/// A time of day window in `HH:MM` format, windows where `end` is before `start` wrap past midnight
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct TimeWindow {
+    pub start: String,
+    pub end: String,
+}
+
+impl TimeWindow {
+    pub fn contains(&self, time: NaiveTime) -> Result<bool, String> {
+        let start = parse_time(&self.start)?;
+        let end = parse_time(&self.end)?;
+
+        if start <= end {
+            Ok(time >= start && time < end)
+        } else {
+            Ok(time >= start || time < end)
+        }
+    }
+}
+
+/// A user defined notification rule, empty filters match everything
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct NotificationRule {
+    #[serde(default)]
+    pub id: String,
+    pub name: String,
+    #[serde(default = "default_enabled")]
+    pub enabled: bool,
+    /// Pipeline event or notification types, such as `invite`, `friendRequest` or `friend-online`
+    #[serde(default)]
+    pub event_types: Vec<String>,
+    #[serde(default)]
+    pub friend_ids: Vec<String>,
+    /// Favorite group names, such as `group_0`
+    #[serde(default)]
+    pub favorite_groups: Vec<String>,
+    #[serde(default)]
+    pub world_ids: Vec<String>,
+    /// When set, the rule only applies inside this window
+    #[serde(default)]
+    pub quiet_hours: Option<TimeWindow>,
+    pub action: RuleAction,
+}
+
+fn default_enabled() -> bool {
+    true
+}
+
+/// An incoming event as seen by the rules engine
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct RuleEvent {
+    pub event_type: String,
+    #[serde(default)]
+    pub user_id: Option<String>,
+    #[serde(default)]
+    pub world_id: Option<String>,
+    /// Favorite groups the sending friend is in
+    #[serde(default)]
+    pub favorite_groups: Vec<String>,
+    /// Time of day override in `HH:MM` format, mostly useful for dry runs
+    #[serde(default)]
+    pub time: Option<String>,
+}
+
+/// The outcome of evaluating an event against the rules
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct RuleDecision {
+    pub rule_id: Option<String>,
+    pub action: RuleAction,
+    pub desktop: bool,
+    pub overlay: bool,
+    pub log: bool,
+}
+
+impl RuleDecision {
+    pub fn from_action(rule_id: Option<String>, action: RuleAction) -> Self {
+        let (desktop, overlay, log) = match action {
+            RuleAction::Notify => (true, true, true),
+            RuleAction::Desktop => (true, false, true),
+            RuleAction::Overlay => (false, true, true),
+            RuleAction::LogOnly => (false, false, true),
+            RuleAction::Suppress => (false, false, false),
+        };
+
+        RuleDecision {
+            rule_id,
+            action,
+            desktop,
+            overlay,
+            log,
+        }
+    }
+}
+
+impl NotificationRule {
+    pub fn validate(&self) -> Result<(), String> {
+        if self.name.trim().is_empty() {
+            return Err("Rule name cannot be empty".to_string());
+        }
+        if let Some(window) = &self.quiet_hours {
+            parse_time(&window.start)?;
+            parse_time(&window.end)?;
+        }
+        Ok(())
+    }
+
+    pub fn matches(&self, event: &RuleEvent, time: NaiveTime) -> bool {
+        if !self.enabled {
+            return false;
+        }
+        if !self.event_types.is_empty() && !self.event_types.contains(&event.event_type) {
+            return false;
+        }
+        if !self.friend_ids.is_empty() && !matches_option(&self.friend_ids, &event.user_id) {
+            return false;
+        }
+        if !self.world_ids.is_empty() && !matches_option(&self.world_ids, &event.world_id) {
+            return false;
+        }
+        if !self.favorite_groups.is_empty()
+            && !self
+                .favorite_groups
+                .iter()
+                .any(|group| event.favorite_groups.contains(group))
+        {
+            return false;
+        }
+        match &self.quiet_hours {
+            Some(window) => window.contains(time).unwrap_or(false),
+            None => true,
+        }
+    }
+}
+
+fn matches_option(values: &[String], value: &Option<String>) -> bool {
+    match value {
+        Some(value) => values.contains(value),
+        None => false,
+    }
+}
+
+pub fn parse_time(time: &str) -> Result<NaiveTime, String> {
+    NaiveTime::parse_from_str(time, "%H:%M")
+        .map_err(|e| format!("Invalid time {}, expected HH:MM: {}", time, e))
+}
+
+/// Evaluates an event against the rules in order, the first matching rule wins
+pub fn evaluate(rules: &[NotificationRule], event: &RuleEvent, time: NaiveTime) -> RuleDecision {
+    match rules.iter().find(|rule| rule.matches(event, time)) {
+        Some(rule) => RuleDecision::from_action(Some(rule.id.clone()), rule.action),
+        None => RuleDecision::from_action(None, RuleAction::Notify),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rule(id: &str, action: RuleAction) -> NotificationRule {
+        NotificationRule {
+            id: id.to_string(),
+            name: id.to_string(),
+            enabled: true,
+            event_types: Vec::new(),
+            friend_ids: Vec::new(),
+            favorite_groups: Vec::new(),
+            world_ids: Vec::new(),
+            quiet_hours: None,
+            action,
+        }
+    }
+
+    fn event(event_type: &str, user_id: &str) -> RuleEvent {
+        RuleEvent {
+            event_type: event_type.to_string(),
+            user_id: Some(user_id.to_string()),
+            world_id: None,
+            favorite_groups: vec!["group_0".to_string()],
+            time: None,
+        }
+    }
+
+    fn time(time: &str) -> NaiveTime {
+        parse_time(time).unwrap()
+    }
+
+    fn window(start: &str, end: &str) -> TimeWindow {
+        TimeWindow {
+            start: start.to_string(),
+            end: end.to_string(),
+        }
+    }
+
+    #[test]
+    fn notifies_without_matching_rules() {
+        let noon = time("12:00");
+        assert_eq!(
+            evaluate(&[], &event("invite", "usr_a"), noon),
+            RuleDecision::from_action(None, RuleAction::Notify)
+        );
+
+        let mut invites = rule("invites", RuleAction::Suppress);
+        invites.event_types = vec!["invite".to_string()];
+        let decision = evaluate(&[invites], &event("friend-online", "usr_a"), noon);
+        assert_eq!(decision.rule_id, None);
+        assert_eq!(decision.action, RuleAction::Notify);
+        assert!(decision.desktop && decision.overlay && decision.log);
+    }
+
+    #[test]
+    fn first_matching_rule_wins() {
+        let noon = time("12:00");
+
+        let mut disabled = rule("disabled", RuleAction::Suppress);
+        disabled.enabled = false;
+        let mut friend = rule("friend", RuleAction::Desktop);
+        friend.friend_ids = vec!["usr_a".to_string()];
+        let mut group = rule("group", RuleAction::Overlay);
+        group.favorite_groups = vec!["group_0".to_string()];
+        let fallback = rule("fallback", RuleAction::LogOnly);
+        let rules = [disabled, friend, group, fallback];
+
+        let decision = evaluate(&rules, &event("invite", "usr_a"), noon);
+        assert_eq!(decision.rule_id.as_deref(), Some("friend"));
+        assert_eq!(decision.action, RuleAction::Desktop);
+
+        let decision = evaluate(&rules, &event("invite", "usr_b"), noon);
+        assert_eq!(decision.rule_id.as_deref(), Some("group"));
+
+        let mut other = event("invite", "usr_b");
+        other.favorite_groups.clear();
+        let decision = evaluate(&rules, &other, noon);
+        assert_eq!(decision.rule_id.as_deref(), Some("fallback"));
+        assert_eq!(decision.action, RuleAction::LogOnly);
+    }
+
+    #[test]
+    fn quiet_hours_limit_rules() {
+        let mut night = rule("night", RuleAction::Suppress);
+        night.quiet_hours = Some(window("22:00", "06:00"));
+        let rules = [night];
+        let event = event("invite", "usr_a");
+
+        assert_eq!(
+            evaluate(&rules, &event, time("23:30")).action,
+            RuleAction::Suppress
+        );
+        assert_eq!(
+            evaluate(&rules, &event, time("12:00")).action,
+            RuleAction::Notify
+        );
+    }
+
+    #[test]
+    fn windows_can_cross_midnight() {
+        let night = window("22:00", "06:00");
+        assert!(night.contains(time("22:00")).unwrap());
+        assert!(night.contains(time("23:30")).unwrap());
+        assert!(night.contains(time("00:00")).unwrap());
+        assert!(night.contains(time("05:59")).unwrap());
+        assert!(!night.contains(time("06:00")).unwrap());
+        assert!(!night.contains(time("12:00")).unwrap());
+
+        let day = window("09:00", "17:00");
+        assert!(day.contains(time("09:00")).unwrap());
+        assert!(!day.contains(time("17:00")).unwrap());
+        assert!(!day.contains(time("23:00")).unwrap());
+
+        assert!(window("25:00", "06:00").contains(time("12:00")).is_err());
+    }
+}
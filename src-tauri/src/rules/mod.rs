@@ -0,0 +1,127 @@
+use chrono::{Local, NaiveTime};
+use serde::{Deserialize, Serialize};
+use std::time::{SystemTime, UNIX_EPOCH};
+use tauri::AppHandle;
+
+use crate::rules::engine::{evaluate, parse_time, NotificationRule, RuleDecision, RuleEvent};
+use crate::settings::{load_setting, save_setting};
+
+pub(crate) mod engine;
+
+const RULES_KEY: &str = "notificationRules";
+
+/// Result of testing a single sample event in a dry run
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct RuleDryRunResult {
+    pub event: RuleEvent,
+    pub matched: bool,
+    pub decision: RuleDecision,
+}
+
+pub fn load_rules(app: &AppHandle) -> Result<Vec<NotificationRule>, String> {
+    Ok(load_setting::<Vec<NotificationRule>>(app, RULES_KEY)?.unwrap_or_default())
+}
+
+fn save_rules(app: &AppHandle, rules: &[NotificationRule]) -> Result<(), String> {
+    save_setting(app, RULES_KEY, rules)
+}
+
+fn event_time(event: &RuleEvent) -> Result<NaiveTime, String> {
+    match &event.time {
+        Some(time) => parse_time(time),
+        None => Ok(Local::now().time()),
+    }
+}
+
+#[tauri::command]
+pub fn get_notification_rules(app: AppHandle) -> Result<Vec<NotificationRule>, String> {
+    load_rules(&app)
+}
+
+/// Adds a rule to the end of the list, a new id is assigned to it
+#[tauri::command]
+pub fn add_notification_rule(
+    app: AppHandle,
+    mut rule: NotificationRule,
+) -> Result<NotificationRule, String> {
+    rule.validate()?;
+
+    let millis = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_err(|e| format!("Failed to get time: {}", e))?
+        .as_millis();
+    rule.id = format!("rule_{}", millis);
+
+    let mut rules = load_rules(&app)?;
+    rules.push(rule.clone());
+    save_rules(&app, &rules)?;
+
+    Ok(rule)
+}
+
+#[tauri::command]
+pub fn update_notification_rule(
+    app: AppHandle,
+    rule: NotificationRule,
+) -> Result<NotificationRule, String> {
+    rule.validate()?;
+
+    let mut rules = load_rules(&app)?;
+    match rules.iter_mut().find(|r| r.id == rule.id) {
+        Some(existing) => *existing = rule.clone(),
+        None => return Err(format!("Rule {} does not exist", rule.id)),
+    }
+    save_rules(&app, &rules)?;
+
+    Ok(rule)
+}
+
+#[tauri::command]
+pub fn delete_notification_rule(app: AppHandle, rule_id: String) -> Result<(), String> {
+    let mut rules = load_rules(&app)?;
+    let count = rules.len();
+    rules.retain(|r| r.id != rule_id);
+
+    if rules.len() == count {
+        return Err(format!("Rule {} does not exist", rule_id));
+    }
+    save_rules(&app, &rules)
+}
+
+/// Decides how an incoming event should be delivered, called for every notification and pipeline event
+#[tauri::command]
+pub fn evaluate_notification_rules(
+    app: AppHandle,
+    event: RuleEvent,
+) -> Result<RuleDecision, String> {
+    let rules = load_rules(&app)?;
+    let time = event_time(&event)?;
+
+    Ok(evaluate(&rules, &event, time))
+}
+
+/// Tests a single rule against sample events without saving it
+#[tauri::command]
+pub fn dry_run_notification_rule(
+    rule: NotificationRule,
+    events: Vec<RuleEvent>,
+) -> Result<Vec<RuleDryRunResult>, String> {
+    rule.validate()?;
+
+    let rules = vec![rule];
+    events
+        .into_iter()
+        .map(|event| {
+            let time = event_time(&event)?;
+            let matched = rules[0].matches(&event, time);
+            let decision = evaluate(&rules, &event, time);
+
+            Ok(RuleDryRunResult {
+                event,
+                matched,
+                decision,
+            })
+        })
+        .collect()
+}
@@ -0,0 +1,41 @@
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use std::path::PathBuf;
+use tauri::AppHandle;
+use tauri_plugin_store::StoreExt;
+
+// Backend access to the same settings store the frontend uses in store.ts
+const SETTINGS_PATH: &str = ".settings.dat";
+
+/// Loads a JSON setting, returns `None` if it has never been saved
+pub fn load_setting<T: DeserializeOwned>(app: &AppHandle, key: &str) -> Result<Option<T>, String> {
+    let store = app
+        .store(PathBuf::from(SETTINGS_PATH))
+        .map_err(|e| format!("Failed to open settings: {}", e))?;
+
+    match store.get(key) {
+        Some(value) => serde_json::from_value::<T>(value)
+            .map(Some)
+            .map_err(|e| format!("Failed to parse setting {}: {}", key, e)),
+        None => Ok(None),
+    }
+}
+
+/// Saves a setting as JSON and flushes the store to disk
+pub fn save_setting<T: Serialize + ?Sized>(
+    app: &AppHandle,
+    key: &str,
+    value: &T,
+) -> Result<(), String> {
+    let store = app
+        .store(PathBuf::from(SETTINGS_PATH))
+        .map_err(|e| format!("Failed to open settings: {}", e))?;
+
+    let value = serde_json::to_value(value)
+        .map_err(|e| format!("Failed to serialize setting {}: {}", key, e))?;
+
+    store.set(key, value);
+    store
+        .save()
+        .map_err(|e| format!("Failed to save settings: {}", e))
+}
@@ -0,0 +1,151 @@
+use chrono::Weekday;
+use serde::{Deserialize, Serialize};
+use tauri::AppHandle;
+
+use crate::db::logs::add_log;
+use crate::rules::engine::parse_time;
+use crate::settings::{load_setting, save_setting};
+use crate::types::user::{CurrentUser, UpdateUser, UserStatus};
+use crate::web::vrc_user::{get_current_user, put_vrc_update_user};
+
+pub(crate) mod scheduler;
+
+const PRESETS_KEY: &str = "statusPresets";
+const SCHEDULE_KEY: &str = "statusSchedule";
+
+/// A named status, applied with a single `users/{userId}` update
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct StatusPreset {
+    pub name: String,
+    pub status: UserStatus,
+    #[serde(default)]
+    pub status_description: String,
+    /// Bio is only replaced when the preset has one
+    #[serde(default)]
+    pub bio: Option<String>,
+}
+
+impl StatusPreset {
+    fn update(&self) -> UpdateUser {
+        UpdateUser {
+            status: Some(self.status),
+            status_description: Some(self.status_description.clone()),
+            bio: self.bio.clone(),
+            ..Default::default()
+        }
+    }
+}
+
+/// Applies `preset` at `time` (`HH:MM`, local time) on each of `days`
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ScheduleEntry {
+    pub preset: String,
+    pub days: Vec<Weekday>,
+    pub time: String,
+    #[serde(default = "default_enabled")]
+    pub enabled: bool,
+}
+
+fn default_enabled() -> bool {
+    true
+}
+
+pub fn load_presets(app: &AppHandle) -> Result<Vec<StatusPreset>, String> {
+    Ok(load_setting::<Vec<StatusPreset>>(app, PRESETS_KEY)?.unwrap_or_default())
+}
+
+pub fn load_schedule(app: &AppHandle) -> Result<Vec<ScheduleEntry>, String> {
+    Ok(load_setting::<Vec<ScheduleEntry>>(app, SCHEDULE_KEY)?.unwrap_or_default())
+}
+
+/// Applies a preset to the current user and records the change in the log table
+pub async fn apply_preset(
+    app: &AppHandle,
+    preset: &StatusPreset,
+    log_type: &str,
+) -> Result<CurrentUser, String> {
+    let update = preset.update();
+    update.validate()?;
+
+    let user = get_current_user(app).await?;
+    let user = put_vrc_update_user(app.clone(), user.id, update).await?;
+
+    let message = format!("Applied status preset {}", preset.name);
+    if let Err(e) = add_log(app, log_type, &message, Some(&user.id), None).await {
+        log::warn!("{}", e);
+    }
+
+    Ok(user)
+}
+
+#[tauri::command]
+pub fn get_status_presets(app: AppHandle) -> Result<Vec<StatusPreset>, String> {
+    load_presets(&app)
+}
+
+/// Saves a preset, replacing any existing preset with the same name
+#[tauri::command]
+pub fn save_status_preset(app: AppHandle, preset: StatusPreset) -> Result<StatusPreset, String> {
+    if preset.name.trim().is_empty() {
+        return Err("Preset name cannot be empty".to_string());
+    }
+    preset.update().validate()?;
+
+    let mut presets = load_presets(&app)?;
+    match presets.iter_mut().find(|p| p.name == preset.name) {
+        Some(existing) => *existing = preset.clone(),
+        None => presets.push(preset.clone()),
+    }
+    save_setting(&app, PRESETS_KEY, &presets)?;
+
+    Ok(preset)
+}
+
+/// Deletes a preset along with any schedule entries using it
+#[tauri::command]
+pub fn delete_status_preset(app: AppHandle, name: String) -> Result<(), String> {
+    let mut presets = load_presets(&app)?;
+    presets.retain(|p| p.name != name);
+    save_setting(&app, PRESETS_KEY, &presets)?;
+
+    let mut schedule = load_schedule(&app)?;
+    schedule.retain(|entry| entry.preset != name);
+    save_setting(&app, SCHEDULE_KEY, &schedule)
+}
+
+#[tauri::command]
+pub async fn apply_status_preset(app: AppHandle, name: String) -> Result<CurrentUser, String> {
+    let presets = load_presets(&app)?;
+    let preset = presets
+        .iter()
+        .find(|p| p.name == name)
+        .ok_or_else(|| format!("Status preset {} does not exist", name))?;
+
+    apply_preset(&app, preset, "Status Preset").await
+}
+
+#[tauri::command]
+pub fn get_status_schedule(app: AppHandle) -> Result<Vec<ScheduleEntry>, String> {
+    load_schedule(&app)
+}
+
+/// Replaces the weekly timetable, every entry must reference an existing preset
+#[tauri::command]
+pub fn set_status_schedule(
+    app: AppHandle,
+    schedule: Vec<ScheduleEntry>,
+) -> Result<Vec<ScheduleEntry>, String> {
+    let presets = load_presets(&app)?;
+
+    for entry in &schedule {
+        parse_time(&entry.time)?;
+        if !presets.iter().any(|p| p.name == entry.preset) {
+            return Err(format!("Status preset {} does not exist", entry.preset));
+        }
+    }
+    save_setting(&app, SCHEDULE_KEY, &schedule)?;
+
+    Ok(schedule)
+}
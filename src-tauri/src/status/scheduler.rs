@@ -0,0 +1,61 @@
+use chrono::{Datelike, Local, NaiveDateTime, Timelike};
+use std::time::Duration;
+use tauri::AppHandle;
+
+use crate::rules::engine::parse_time;
+use crate::status::{apply_preset, load_presets, load_schedule, ScheduleEntry};
+
+const TICK: Duration = Duration::from_secs(20);
+
+fn is_due(entry: &ScheduleEntry, now: NaiveDateTime) -> bool {
+    if !entry.enabled || !entry.days.contains(&now.weekday()) {
+        return false;
+    }
+
+    match parse_time(&entry.time) {
+        Ok(time) => time.hour() == now.hour() && time.minute() == now.minute(),
+        Err(_) => false,
+    }
+}
+
+/// Checks the status schedule a few times a minute and applies presets that are due
+pub fn start(app: AppHandle) {
+    tauri::async_runtime::spawn(async move {
+        // The minute a preset was last applied in, so each slot only fires once
+        let mut last_applied: Option<NaiveDateTime> = None;
+
+        loop {
+            tokio::time::sleep(TICK).await;
+
+            let now = Local::now().naive_local();
+            let minute = now.with_second(0).and_then(|t| t.with_nanosecond(0));
+            if minute.is_none() || minute == last_applied {
+                continue;
+            }
+
+            let schedule = match load_schedule(&app) {
+                Ok(schedule) => schedule,
+                Err(e) => {
+                    log::warn!("{}", e);
+                    continue;
+                }
+            };
+
+            // Later entries win when several are due in the same minute
+            let Some(entry) = schedule.iter().rev().find(|entry| is_due(entry, now)) else {
+                continue;
+            };
+            last_applied = minute;
+
+            let presets = load_presets(&app).unwrap_or_default();
+            match presets.iter().find(|p| p.name == entry.preset) {
+                Some(preset) => {
+                    if let Err(e) = apply_preset(&app, preset, "Scheduled Status").await {
+                        log::warn!("Failed to apply scheduled preset {}: {}", preset.name, e);
+                    }
+                }
+                None => log::warn!("Scheduled preset {} does not exist", entry.preset),
+            }
+        }
+    });
+}
@@ -0,0 +1,119 @@
+use serde::{Deserialize, Serialize};
+
+/// An asset bundle of an avatar or world for a single platform
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct UnityPackage {
+    pub id: String,
+    #[serde(default)]
+    pub platform: String,
+    #[serde(default)]
+    pub unity_version: String,
+    #[serde(default)]
+    pub asset_version: u32,
+}
+
+/// A VRChat avatar as returned by `avatars/{avatarId}` and the avatar list endpoints
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Avatar {
+    pub id: String,
+    pub name: String,
+    #[serde(default)]
+    pub description: String,
+    #[serde(default)]
+    pub author_id: String,
+    #[serde(default)]
+    pub author_name: String,
+    #[serde(default)]
+    pub image_url: String,
+    #[serde(default)]
+    pub thumbnail_image_url: String,
+    #[serde(default)]
+    pub release_status: String,
+    #[serde(default)]
+    pub tags: Vec<String>,
+    #[serde(default)]
+    pub featured: bool,
+    #[serde(default)]
+    pub unity_packages: Vec<UnityPackage>,
+    #[serde(rename = "created_at", default)]
+    pub created_at: Option<String>,
+    #[serde(rename = "updated_at", default)]
+    pub updated_at: Option<String>,
+}
+
+impl Avatar {
+    pub fn supports_platform(&self, platform: AvatarPlatform) -> bool {
+        self.unity_packages
+            .iter()
+            .any(|package| package.platform == platform.unity_platform())
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum AvatarPlatform {
+    Pc,
+    Quest,
+}
+
+impl AvatarPlatform {
+    /// The platform name used by unity packages
+    pub fn unity_platform(&self) -> &'static str {
+        match self {
+            AvatarPlatform::Pc => "standalonewindows",
+            AvatarPlatform::Quest => "android",
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum ReleaseStatus {
+    Public,
+    Private,
+}
+
+/// Body for `avatars/{avatarId}`, fields left as `None` are not changed
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateAvatar {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tags: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub release_status: Option<ReleaseStatus>,
+}
+
+/// Filters for searching the current user's avatars, every field is optional
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(rename_all = "camelCase", default)]
+pub struct AvatarSearch {
+    /// Matched case insensitively against the name
+    pub query: Option<String>,
+    pub tag: Option<String>,
+    pub platform: Option<AvatarPlatform>,
+}
+
+impl AvatarSearch {
+    pub fn matches(&self, avatar: &Avatar) -> bool {
+        if let Some(query) = &self.query {
+            if !avatar.name.to_lowercase().contains(&query.to_lowercase()) {
+                return false;
+            }
+        }
+        if let Some(tag) = &self.tag {
+            if !avatar.tags.contains(tag) {
+                return false;
+            }
+        }
+        match self.platform {
+            Some(platform) => avatar.supports_platform(platform),
+            None => true,
+        }
+    }
+}
@@ -0,0 +1,124 @@
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum FavoriteType {
+    World,
+    Friend,
+    Avatar,
+}
+
+impl fmt::Display for FavoriteType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FavoriteType::World => write!(f, "world"),
+            FavoriteType::Friend => write!(f, "friend"),
+            FavoriteType::Avatar => write!(f, "avatar"),
+        }
+    }
+}
+
+/// A single favorite, `favorite_id` is the id of the favorited world, user or avatar
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Favorite {
+    pub id: String,
+    pub favorite_id: String,
+    #[serde(default)]
+    pub tags: Vec<String>,
+    #[serde(rename = "type")]
+    pub favorite_type: FavoriteType,
+}
+
+/// A favorite group, favorites reference their group by its `name` through their tags
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct FavoriteGroup {
+    pub id: String,
+    pub name: String,
+    #[serde(default)]
+    pub display_name: String,
+    #[serde(default)]
+    pub owner_id: Option<String>,
+    #[serde(rename = "type")]
+    pub favorite_type: FavoriteType,
+    #[serde(default)]
+    pub visibility: Option<String>,
+    #[serde(default)]
+    pub tags: Vec<String>,
+}
+
+/// A favorite group along with how many favorites it holds and how many it can hold
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct FavoriteGroupSummary {
+    #[serde(flatten)]
+    pub group: FavoriteGroup,
+    pub count: u32,
+    pub capacity: u32,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct FavoriteTypeLimits {
+    #[serde(default)]
+    pub avatar: u32,
+    #[serde(default)]
+    pub friend: u32,
+    #[serde(default)]
+    pub world: u32,
+}
+
+impl FavoriteTypeLimits {
+    pub fn get(&self, favorite_type: FavoriteType) -> u32 {
+        match favorite_type {
+            FavoriteType::World => self.world,
+            FavoriteType::Friend => self.friend,
+            FavoriteType::Avatar => self.avatar,
+        }
+    }
+}
+
+/// Response of `auth/user/favoritelimits`
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct FavoriteLimits {
+    #[serde(default)]
+    pub max_favorite_groups: FavoriteTypeLimits,
+    #[serde(default)]
+    pub max_favorites_per_group: FavoriteTypeLimits,
+}
+
+/// Body for `favorites`
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct AddFavorite {
+    #[serde(rename = "type")]
+    pub favorite_type: FavoriteType,
+    pub favorite_id: String,
+    pub tags: Vec<String>,
+}
+
+/// Errors returned by favorite commands, serialized with a `kind` so the frontend can match on it
+#[derive(Serialize, Debug)]
+#[serde(tag = "kind", rename_all = "camelCase")]
+pub enum FavoriteError {
+    #[serde(rename_all = "camelCase")]
+    GroupFull { group: String, capacity: u32 },
+    #[serde(rename_all = "camelCase")]
+    UnknownGroup { group: String },
+    #[serde(rename_all = "camelCase")]
+    Request { message: String },
+    /// A move removed the favorite but couldn't add it to either group
+    #[serde(rename_all = "camelCase")]
+    Unfavorited {
+        favorite_id: String,
+        message: String,
+    },
+}
+
+impl From<String> for FavoriteError {
+    fn from(message: String) -> Self {
+        FavoriteError::Request { message }
+    }
+}
@@ -0,0 +1,13 @@
+use serde::{Deserialize, Serialize};
+
+/// Response of `user/{userId}/friendStatus`
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct FriendStatus {
+    #[serde(default)]
+    pub incoming_request: bool,
+    #[serde(default)]
+    pub is_friend: bool,
+    #[serde(default)]
+    pub outgoing_request: bool,
+}
@@ -0,0 +1,379 @@
+use crate::types::world::World;
+use serde::{Deserialize, Serialize};
+
+/// A group as returned by `groups/{groupId}`
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Group {
+    pub id: String,
+    pub name: String,
+    #[serde(default)]
+    pub short_code: String,
+    #[serde(default)]
+    pub discriminator: String,
+    #[serde(default)]
+    pub description: String,
+    #[serde(default)]
+    pub icon_url: Option<String>,
+    #[serde(default)]
+    pub banner_url: Option<String>,
+    #[serde(default)]
+    pub privacy: String,
+    #[serde(default)]
+    pub owner_id: String,
+    #[serde(default)]
+    pub member_count: u32,
+    #[serde(default)]
+    pub online_member_count: u32,
+    /// `open`, `request`, `invite` or `closed`
+    #[serde(default)]
+    pub join_state: String,
+    #[serde(default)]
+    pub membership_status: Option<String>,
+    #[serde(default)]
+    pub tags: Vec<String>,
+    #[serde(default)]
+    pub galleries: Vec<GroupGallery>,
+    /// Only included when the current user is a member
+    #[serde(default)]
+    pub my_member: Option<GroupMyMember>,
+    #[serde(default)]
+    pub roles: Option<Vec<GroupRole>>,
+}
+
+impl Group {
+    /// The current user's membership status, such as `member`, `requested`, `invited` or `inactive`
+    pub fn membership_status(&self) -> &str {
+        match (&self.my_member, &self.membership_status) {
+            (Some(member), _) if !member.membership_status.is_empty() => &member.membership_status,
+            (_, Some(status)) => status,
+            _ => "inactive",
+        }
+    }
+
+    pub fn is_member(&self) -> bool {
+        self.membership_status() == "member"
+    }
+
+    /// Checks a group permission such as `group-bans-manage`, owners have the `*` permission
+    pub fn has_permission(&self, permission: &str) -> bool {
+        match &self.my_member {
+            Some(member) => member
+                .permissions
+                .iter()
+                .any(|p| p == "*" || p == permission),
+            None => false,
+        }
+    }
+
+    pub fn require_permission(&self, permission: &str) -> Result<(), String> {
+        if !self.has_permission(permission) {
+            return Err(format!(
+                "You don't have the {} permission in {}",
+                permission, self.name
+            ));
+        }
+        Ok(())
+    }
+}
+
+/// The current user's membership of a group
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct GroupMyMember {
+    pub id: String,
+    pub group_id: String,
+    pub user_id: String,
+    #[serde(default)]
+    pub role_ids: Vec<String>,
+    #[serde(default)]
+    pub membership_status: String,
+    #[serde(default)]
+    pub is_subscribed_to_announcements: bool,
+    #[serde(default)]
+    pub visibility: String,
+    #[serde(default)]
+    pub is_representing: bool,
+    #[serde(default)]
+    pub joined_at: Option<String>,
+    #[serde(default)]
+    pub permissions: Vec<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct GroupGallery {
+    pub id: String,
+    pub name: String,
+    #[serde(default)]
+    pub description: String,
+    #[serde(default)]
+    pub members_only: bool,
+    #[serde(default)]
+    pub role_ids_to_view: Option<Vec<String>>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct GroupRole {
+    pub id: String,
+    pub group_id: String,
+    pub name: String,
+    #[serde(default)]
+    pub description: String,
+    #[serde(default)]
+    pub is_self_assignable: bool,
+    #[serde(default)]
+    pub permissions: Vec<String>,
+    #[serde(default)]
+    pub is_management_role: bool,
+    #[serde(default)]
+    pub requires_two_factor: bool,
+    #[serde(default)]
+    pub order: i32,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct GroupGalleryImage {
+    pub id: String,
+    pub group_id: String,
+    pub gallery_id: String,
+    #[serde(default)]
+    pub image_url: String,
+    #[serde(default)]
+    pub submitted_by_user_id: String,
+    #[serde(default)]
+    pub approved: bool,
+    #[serde(default)]
+    pub created_at: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct GroupMemberUser {
+    pub id: String,
+    pub display_name: String,
+    #[serde(default)]
+    pub thumbnail_url: Option<String>,
+    #[serde(default)]
+    pub icon_url: Option<String>,
+}
+
+/// A member as returned by `groups/{groupId}/members`
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct GroupMember {
+    pub id: String,
+    pub group_id: String,
+    pub user_id: String,
+    #[serde(default)]
+    pub is_representing: bool,
+    pub user: GroupMemberUser,
+    #[serde(default)]
+    pub role_ids: Vec<String>,
+    #[serde(default)]
+    pub m_role_ids: Vec<String>,
+    #[serde(default)]
+    pub joined_at: Option<String>,
+    #[serde(default)]
+    pub membership_status: String,
+    #[serde(default)]
+    pub visibility: String,
+    #[serde(default)]
+    pub manager_notes: Option<String>,
+}
+
+/// A running group instance as returned by `groups/{groupId}/instances`
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct GroupInstance {
+    pub instance_id: String,
+    pub location: String,
+    pub world: World,
+    #[serde(default)]
+    pub member_count: u32,
+}
+
+/// A group post, announcements are posts too
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct GroupPost {
+    pub id: String,
+    pub group_id: String,
+    #[serde(default)]
+    pub author_id: String,
+    #[serde(default)]
+    pub editor_id: Option<String>,
+    #[serde(default)]
+    pub visibility: String,
+    #[serde(default)]
+    pub role_ids: Vec<String>,
+    #[serde(default)]
+    pub title: String,
+    #[serde(default)]
+    pub text: String,
+    #[serde(default)]
+    pub image_url: Option<String>,
+    #[serde(default)]
+    pub created_at: Option<String>,
+    #[serde(default)]
+    pub updated_at: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct GroupPosts {
+    pub posts: Vec<GroupPost>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct CalendarEvent {
+    pub id: String,
+    pub owner_id: String,
+    pub title: String,
+    #[serde(default)]
+    pub description: String,
+    pub starts_at: String,
+    pub ends_at: String,
+    #[serde(default)]
+    pub category: String,
+    #[serde(default)]
+    pub access_type: String,
+    #[serde(default)]
+    pub image_url: Option<String>,
+    #[serde(default)]
+    pub tags: Vec<String>,
+    #[serde(default)]
+    pub interested_user_count: u32,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct CalendarEvents {
+    pub results: Vec<CalendarEvent>,
+    #[serde(default)]
+    pub total_count: u32,
+    #[serde(default)]
+    pub has_next: bool,
+}
+
+/// Who can see that the current user is in a group
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum GroupMemberVisibility {
+    Visible,
+    Friends,
+    Hidden,
+}
+
+/// The body of `PUT groups/{groupId}/members/{userId}`, unset fields are left unchanged
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateGroupMember {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub visibility: Option<GroupMemberVisibility>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_subscribed_to_announcements: Option<bool>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct GroupRepresentation {
+    pub is_representing: bool,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum JoinRequestAction {
+    Accept,
+    Reject,
+}
+
+/// The body of `PUT groups/{groupId}/requests/{userId}`
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct RespondGroupJoinRequest {
+    pub action: JoinRequestAction,
+    /// Blocks the user from requesting again, only used when rejecting
+    pub block: bool,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct BanGroupMember {
+    pub user_id: String,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct GroupAuditLog {
+    pub id: String,
+    #[serde(rename = "created_at", default)]
+    pub created_at: Option<String>,
+    pub group_id: String,
+    #[serde(default)]
+    pub actor_id: String,
+    #[serde(default)]
+    pub actor_display_name: Option<String>,
+    #[serde(default)]
+    pub target_id: Option<String>,
+    /// Such as `group.user.ban` or `group.instance.create`
+    pub event_type: String,
+    #[serde(default)]
+    pub description: String,
+    #[serde(default)]
+    pub data: serde_json::Value,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct GroupAuditLogs {
+    pub results: Vec<GroupAuditLog>,
+    #[serde(default)]
+    pub total_count: u32,
+    #[serde(default)]
+    pub has_next: bool,
+}
+
+/// Filters for the group audit log, unset filters match everything
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct GroupAuditLogFilter {
+    #[serde(default)]
+    pub start_date: Option<String>,
+    #[serde(default)]
+    pub end_date: Option<String>,
+    #[serde(default)]
+    pub actor_ids: Vec<String>,
+    #[serde(default)]
+    pub event_types: Vec<String>,
+    #[serde(default)]
+    pub target_ids: Vec<String>,
+    #[serde(default)]
+    pub offset: u16,
+}
+
+impl GroupAuditLogFilter {
+    pub fn query(&self) -> Vec<(&'static str, String)> {
+        let mut query = vec![
+            ("n", "100".to_string()),
+            ("offset", self.offset.to_string()),
+        ];
+        if let Some(start_date) = &self.start_date {
+            query.push(("startDate", start_date.clone()));
+        }
+        if let Some(end_date) = &self.end_date {
+            query.push(("endDate", end_date.clone()));
+        }
+        if !self.actor_ids.is_empty() {
+            query.push(("actorIds", self.actor_ids.join(",")));
+        }
+        if !self.event_types.is_empty() {
+            query.push(("eventTypes", self.event_types.join(",")));
+        }
+        if !self.target_ids.is_empty() {
+            query.push(("targetIds", self.target_ids.join(",")));
+        }
+        query
+    }
+}
@@ -0,0 +1,300 @@
+use crate::types::user::LimitedUser;
+use crate::types::world::World;
+use serde::{Deserialize, Serialize};
+
+/// Instance access types as shown in the VRChat client
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InstanceAccess {
+    #[serde(rename = "public")]
+    Public,
+    #[serde(rename = "friends+")]
+    FriendsPlus,
+    #[serde(rename = "friends")]
+    Friends,
+    #[serde(rename = "invite+")]
+    InvitePlus,
+    #[serde(rename = "invite")]
+    Invite,
+    #[serde(rename = "group")]
+    Group,
+    #[serde(rename = "group-public")]
+    GroupPublic,
+    #[serde(rename = "group+")]
+    GroupPlus,
+}
+
+impl InstanceAccess {
+    pub fn is_group(&self) -> bool {
+        matches!(
+            self,
+            InstanceAccess::Group | InstanceAccess::GroupPublic | InstanceAccess::GroupPlus
+        )
+    }
+
+    /// The instance `type` used by the API
+    pub fn instance_type(&self) -> &'static str {
+        match self {
+            InstanceAccess::Public => "public",
+            InstanceAccess::FriendsPlus => "hidden",
+            InstanceAccess::Friends => "friends",
+            InstanceAccess::InvitePlus | InstanceAccess::Invite => "private",
+            InstanceAccess::Group | InstanceAccess::GroupPublic | InstanceAccess::GroupPlus => {
+                "group"
+            }
+        }
+    }
+
+    /// The `groupAccessType` used by the API, only set for group instances
+    pub fn group_access_type(&self) -> Option<GroupAccessType> {
+        match self {
+            InstanceAccess::Group => Some(GroupAccessType::Members),
+            InstanceAccess::GroupPlus => Some(GroupAccessType::Plus),
+            InstanceAccess::GroupPublic => Some(GroupAccessType::Public),
+            _ => None,
+        }
+    }
+}
+
+/// Who can join a group instance
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum GroupAccessType {
+    Members,
+    Plus,
+    Public,
+}
+
+impl GroupAccessType {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            GroupAccessType::Members => "members",
+            GroupAccessType::Plus => "plus",
+            GroupAccessType::Public => "public",
+        }
+    }
+
+    pub fn parse(value: &str) -> Result<Self, String> {
+        match value {
+            "members" => Ok(GroupAccessType::Members),
+            "plus" => Ok(GroupAccessType::Plus),
+            "public" => Ok(GroupAccessType::Public),
+            _ => Err(format!("Unknown group access type {}", value)),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum InstanceRegion {
+    /// US West
+    Us,
+    /// US East
+    Use,
+    Eu,
+    Jp,
+}
+
+/// Options for creating a new instance
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateInstance {
+    pub world_id: String,
+    pub access: InstanceAccess,
+    pub region: InstanceRegion,
+    /// The owning user or group, defaults to the current user for non group instances
+    #[serde(default)]
+    pub owner_id: Option<String>,
+    /// Group roles allowed to join, only used by group instances
+    #[serde(default)]
+    pub role_ids: Vec<String>,
+    #[serde(default)]
+    pub queue_enabled: bool,
+    #[serde(default)]
+    pub age_gate: bool,
+}
+
+impl CreateInstance {
+    pub fn validate(&self) -> Result<(), String> {
+        if !self.world_id.starts_with("wrld_") {
+            return Err(format!("{} is not a world id", self.world_id));
+        }
+
+        match (&self.owner_id, self.access.is_group()) {
+            (None, true) => return Err("Group instances need a group owner".to_string()),
+            (Some(owner), true) if !owner.starts_with("grp_") => {
+                return Err(format!("{} is not a group id", owner))
+            }
+            (Some(owner), false) if !owner.starts_with("usr_") => {
+                return Err(format!("{} is not a user id", owner))
+            }
+            _ => {}
+        }
+
+        if !self.role_ids.is_empty() && !self.access.is_group() {
+            return Err("Role restrictions are only available for group instances".to_string());
+        }
+
+        Ok(())
+    }
+}
+
+/// The body of `POST instances`
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateInstanceBody {
+    pub world_id: String,
+    #[serde(rename = "type")]
+    pub instance_type: String,
+    pub region: InstanceRegion,
+    pub owner_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub group_access_type: Option<GroupAccessType>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub role_ids: Vec<String>,
+    pub can_request_invite: bool,
+    pub queue_enabled: bool,
+    pub age_gate: bool,
+}
+
+impl CreateInstanceBody {
+    pub fn new(create: CreateInstance, owner_id: String) -> Self {
+        CreateInstanceBody {
+            instance_type: create.access.instance_type().to_string(),
+            group_access_type: create.access.group_access_type(),
+            can_request_invite: create.access == InstanceAccess::InvitePlus,
+            world_id: create.world_id,
+            region: create.region,
+            owner_id,
+            role_ids: create.role_ids,
+            queue_enabled: create.queue_enabled,
+            age_gate: create.age_gate,
+        }
+    }
+}
+
+/// A VRChat instance as returned by `instances/{location}`
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Instance {
+    pub id: String,
+    pub location: String,
+    pub instance_id: String,
+    pub world_id: String,
+    #[serde(default)]
+    pub world: Option<World>,
+    #[serde(default)]
+    pub name: String,
+    #[serde(rename = "type", default)]
+    pub instance_type: String,
+    #[serde(default)]
+    pub region: String,
+    #[serde(default)]
+    pub owner_id: Option<String>,
+    #[serde(default)]
+    pub group_access_type: Option<String>,
+    #[serde(default)]
+    pub role_restricted: bool,
+    /// Roles allowed into a role restricted group instance
+    #[serde(default)]
+    pub role_ids: Vec<String>,
+    #[serde(default)]
+    pub can_request_invite: bool,
+    #[serde(default)]
+    pub age_gate: bool,
+    /// The only snake case field of the instance model
+    #[serde(rename = "n_users", default)]
+    pub n_users: u32,
+    #[serde(default)]
+    pub capacity: u32,
+    #[serde(default)]
+    pub queue_enabled: bool,
+    #[serde(default)]
+    pub queue_size: u32,
+    #[serde(default)]
+    pub closed_at: Option<String>,
+    #[serde(default)]
+    pub hard_close: Option<bool>,
+    #[serde(default)]
+    pub short_name: Option<String>,
+    #[serde(default)]
+    pub secure_name: Option<String>,
+    /// Only included when the current user is allowed to see who is in the instance
+    #[serde(default)]
+    pub users: Option<Vec<LimitedUser>>,
+}
+
+/// The response of `instances/{location}/shortName`
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct InstanceShortName {
+    #[serde(default)]
+    pub short_name: Option<String>,
+    #[serde(default)]
+    pub secure_name: Option<String>,
+}
+
+/// A newly created instance and the links that can be shared to it
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct CreatedInstance {
+    pub location: String,
+    pub short_name: Option<String>,
+    pub launch_url: Option<String>,
+    pub instance: Instance,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INSTANCE: &str = r#"{
+        "active": true,
+        "canRequestInvite": false,
+        "capacity": 32,
+        "closedAt": null,
+        "full": false,
+        "hardClose": null,
+        "id": "wrld_4cf554b4-430c-4f8f-b53e-1f294eed230b:12345~group(grp_71a7ff59-112c-4e78-a990-c7cc650776e5)~groupAccessType(members)~region(eu)",
+        "instanceId": "12345~group(grp_71a7ff59-112c-4e78-a990-c7cc650776e5)~groupAccessType(members)~region(eu)",
+        "location": "wrld_4cf554b4-430c-4f8f-b53e-1f294eed230b:12345~group(grp_71a7ff59-112c-4e78-a990-c7cc650776e5)~groupAccessType(members)~region(eu)",
+        "n_users": 31,
+        "name": "12345",
+        "ownerId": "grp_71a7ff59-112c-4e78-a990-c7cc650776e5",
+        "permanent": false,
+        "photonRegion": "eu",
+        "platforms": { "android": 4, "standalonewindows": 27 },
+        "queueEnabled": true,
+        "queueSize": 2,
+        "recommendedCapacity": 16,
+        "region": "eu",
+        "roleRestricted": true,
+        "roleIds": ["grol_459d3911-f672-44bc-b84d-e54ffe7960fe"],
+        "groupAccessType": "members",
+        "ageGate": false,
+        "secureName": "abcdefgh",
+        "shortName": null,
+        "strict": false,
+        "tags": [],
+        "type": "group",
+        "userCount": 31,
+        "worldId": "wrld_4cf554b4-430c-4f8f-b53e-1f294eed230b"
+    }"#;
+
+    #[test]
+    fn deserializes_instance() {
+        let instance: Instance = serde_json::from_str(INSTANCE).unwrap();
+
+        assert_eq!(instance.n_users, 31);
+        assert_eq!(instance.capacity, 32);
+        assert!(instance.queue_enabled);
+        assert_eq!(instance.queue_size, 2);
+        assert_eq!(instance.instance_type, "group");
+        assert_eq!(instance.region, "eu");
+        assert_eq!(instance.group_access_type.as_deref(), Some("members"));
+        assert!(instance.role_restricted);
+        assert_eq!(instance.role_ids.len(), 1);
+        assert_eq!(instance.closed_at, None);
+        assert_eq!(instance.secure_name.as_deref(), Some("abcdefgh"));
+        assert!(instance.users.is_none());
+    }
+}
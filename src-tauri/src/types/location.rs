@@ -0,0 +1,345 @@
+use crate::types::instance::{GroupAccessType, InstanceAccess};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// An instance location such as `wrld_x:12345~hidden(usr_y)~region(eu)~nonce(z)`
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct Location {
+    pub world_id: String,
+    pub instance_name: String,
+    pub access: InstanceAccess,
+    /// The user owning a friends+, friends or invite instance
+    pub owner_id: Option<String>,
+    /// Kept as sent so regions added after this was written still parse,
+    /// missing on old locations, VRChat treats those as `us`
+    pub region: Option<String>,
+    pub group_id: Option<String>,
+    /// Missing on some old group locations, which are members only
+    pub group_access_type: Option<GroupAccessType>,
+    pub nonce: Option<String>,
+    pub strict: bool,
+    pub can_request_invite: bool,
+    pub age_gate: bool,
+    /// Modifiers we don't know about, kept so the location renders back unchanged
+    #[serde(default)]
+    pub unknown: Vec<String>,
+}
+
+/// Where a user is, friend and user models use the special values for anything that isn't an instance
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(tag = "kind", rename_all = "camelCase")]
+pub enum UserLocation {
+    Offline,
+    Private,
+    Traveling,
+    Instance(Location),
+}
+
+impl UserLocation {
+    pub fn parse(location: &str) -> Result<Self, String> {
+        match location {
+            "" | "offline" => Ok(UserLocation::Offline),
+            "private" => Ok(UserLocation::Private),
+            "traveling" => Ok(UserLocation::Traveling),
+            _ => Ok(UserLocation::Instance(Location::parse(location)?)),
+        }
+    }
+}
+
+/// Splits `name(value)` into its name and value
+fn split_modifier(modifier: &str) -> (&str, Option<&str>) {
+    match modifier.split_once('(') {
+        Some((name, value)) if value.ends_with(')') => (name, Some(&value[..value.len() - 1])),
+        _ => (modifier, None),
+    }
+}
+
+fn required<'a>(location: &str, name: &str, value: Option<&'a str>) -> Result<&'a str, String> {
+    match value {
+        Some(value) if !value.is_empty() => Ok(value),
+        _ => Err(format!(
+            "Location {} has an empty {} modifier",
+            location, name
+        )),
+    }
+}
+
+impl Location {
+    /// Parses an instance location, special values like `offline` or `private` are rejected
+    pub fn parse(location: &str) -> Result<Self, String> {
+        match location {
+            "" | "offline" | "private" | "traveling" => {
+                return Err(format!("{} is not an instance location", location))
+            }
+            _ => {}
+        }
+
+        // Locations end up in request paths, none of these appear in real ones
+        if location
+            .chars()
+            .any(|c| c.is_whitespace() || matches!(c, '/' | '?' | '#' | '%'))
+        {
+            return Err(format!("Location {} has invalid characters", location));
+        }
+
+        let (world_id, instance) = location
+            .split_once(':')
+            .ok_or_else(|| format!("Location {} is missing an instance id", location))?;
+
+        if !world_id.starts_with("wrld_") || world_id.len() <= "wrld_".len() {
+            return Err(format!("Location {} has an invalid world id", location));
+        }
+
+        let mut parts = instance.split('~');
+        let instance_name = parts.next().unwrap_or_default();
+        if instance_name.is_empty() {
+            return Err(format!("Location {} is missing an instance name", location));
+        }
+
+        let mut parsed = Location {
+            world_id: world_id.to_string(),
+            instance_name: instance_name.to_string(),
+            access: InstanceAccess::Public,
+            owner_id: None,
+            region: None,
+            group_id: None,
+            group_access_type: None,
+            nonce: None,
+            strict: false,
+            can_request_invite: false,
+            age_gate: false,
+            unknown: Vec::new(),
+        };
+
+        for modifier in parts {
+            let (name, value) = split_modifier(modifier);
+            match name {
+                "hidden" | "friends" | "private" => {
+                    parsed.owner_id = Some(required(location, name, value)?.to_string());
+                    parsed.access = match name {
+                        "hidden" => InstanceAccess::FriendsPlus,
+                        "friends" => InstanceAccess::Friends,
+                        _ => InstanceAccess::Invite,
+                    };
+                }
+                "group" => {
+                    parsed.group_id = Some(required(location, name, value)?.to_string());
+                    parsed.access = InstanceAccess::Group;
+                }
+                "groupAccessType" => {
+                    parsed.group_access_type =
+                        Some(GroupAccessType::parse(required(location, name, value)?)?);
+                }
+                "region" => parsed.region = Some(required(location, name, value)?.to_string()),
+                "nonce" => parsed.nonce = Some(required(location, name, value)?.to_string()),
+                "strict" => parsed.strict = true,
+                "canRequestInvite" => parsed.can_request_invite = true,
+                "ageGate" => parsed.age_gate = true,
+                _ => parsed.unknown.push(modifier.to_string()),
+            }
+        }
+
+        // The access type of private and group instances depends on other modifiers
+        parsed.access = match (parsed.access, parsed.group_access_type) {
+            (InstanceAccess::Invite, _) if parsed.can_request_invite => InstanceAccess::InvitePlus,
+            (InstanceAccess::Group, Some(GroupAccessType::Plus)) => InstanceAccess::GroupPlus,
+            (InstanceAccess::Group, Some(GroupAccessType::Public)) => InstanceAccess::GroupPublic,
+            (access, _) => access,
+        };
+
+        Ok(parsed)
+    }
+}
+
+impl fmt::Display for Location {
+    /// Renders the location in the order VRChat uses
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}", self.world_id, self.instance_name)?;
+
+        let owner = self.owner_id.as_deref().unwrap_or_default();
+        match self.access {
+            InstanceAccess::Public => {}
+            InstanceAccess::FriendsPlus => write!(f, "~hidden({})", owner)?,
+            InstanceAccess::Friends => write!(f, "~friends({})", owner)?,
+            InstanceAccess::Invite | InstanceAccess::InvitePlus => {
+                write!(f, "~private({})", owner)?
+            }
+            InstanceAccess::Group | InstanceAccess::GroupPublic | InstanceAccess::GroupPlus => {
+                write!(
+                    f,
+                    "~group({})",
+                    self.group_id.as_deref().unwrap_or_default()
+                )?
+            }
+        }
+
+        if let Some(group_access_type) = self.group_access_type {
+            write!(f, "~groupAccessType({})", group_access_type.as_str())?;
+        }
+        if self.can_request_invite {
+            write!(f, "~canRequestInvite")?;
+        }
+        if let Some(region) = &self.region {
+            write!(f, "~region({})", region)?;
+        }
+        if let Some(nonce) = &self.nonce {
+            write!(f, "~nonce({})", nonce)?;
+        }
+        if self.age_gate {
+            write!(f, "~ageGate")?;
+        }
+        if self.strict {
+            write!(f, "~strict")?;
+        }
+        for modifier in &self.unknown {
+            write!(f, "~{}", modifier)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl fmt::Display for UserLocation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            UserLocation::Offline => write!(f, "offline"),
+            UserLocation::Private => write!(f, "private"),
+            UserLocation::Traveling => write!(f, "traveling"),
+            UserLocation::Instance(location) => write!(f, "{}", location),
+        }
+    }
+}
+
+/// Parses a location string so the frontend doesn't have to compare raw strings
+#[tauri::command]
+pub fn parse_location(location: String) -> Result<UserLocation, String> {
+    UserLocation::parse(&location)
+}
+
+/// Renders a location back to its canonical string
+#[tauri::command]
+pub fn build_location(location: Location) -> String {
+    location.to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn round_trip(location: &str) -> Location {
+        let parsed = Location::parse(location).unwrap();
+        assert_eq!(parsed.to_string(), location);
+        parsed
+    }
+
+    #[test]
+    fn round_trips_access_types() {
+        let cases = [
+            ("wrld_a:1~region(eu)", InstanceAccess::Public),
+            (
+                "wrld_a:1~hidden(usr_b)~region(us)",
+                InstanceAccess::FriendsPlus,
+            ),
+            (
+                "wrld_a:1~friends(usr_b)~region(jp)",
+                InstanceAccess::Friends,
+            ),
+            (
+                "wrld_a:1~private(usr_b)~region(use)",
+                InstanceAccess::Invite,
+            ),
+            (
+                "wrld_a:1~private(usr_b)~canRequestInvite~region(eu)",
+                InstanceAccess::InvitePlus,
+            ),
+            ("wrld_a:1~group(grp_c)~region(eu)", InstanceAccess::Group),
+            (
+                "wrld_a:1~group(grp_c)~groupAccessType(members)~region(eu)",
+                InstanceAccess::Group,
+            ),
+            (
+                "wrld_a:1~group(grp_c)~groupAccessType(plus)~region(eu)",
+                InstanceAccess::GroupPlus,
+            ),
+            (
+                "wrld_a:1~group(grp_c)~groupAccessType(public)~region(eu)",
+                InstanceAccess::GroupPublic,
+            ),
+        ];
+
+        for (location, access) in cases {
+            assert_eq!(round_trip(location).access, access, "{}", location);
+        }
+    }
+
+    #[test]
+    fn round_trips_modifiers() {
+        let parsed = round_trip("wrld_a:1~hidden(usr_b)~region(eu)~nonce(n)~ageGate~strict");
+        assert_eq!(parsed.owner_id.as_deref(), Some("usr_b"));
+        assert_eq!(parsed.region.as_deref(), Some("eu"));
+        assert_eq!(parsed.nonce.as_deref(), Some("n"));
+        assert!(parsed.age_gate);
+        assert!(parsed.strict);
+
+        let parsed = round_trip("wrld_a:1~group(grp_c)~groupAccessType(plus)~canRequestInvite");
+        assert_eq!(parsed.group_id.as_deref(), Some("grp_c"));
+        assert_eq!(parsed.group_access_type, Some(GroupAccessType::Plus));
+        assert!(parsed.can_request_invite);
+        assert_eq!(parsed.region, None);
+
+        let parsed = round_trip("wrld_a:1");
+        assert_eq!(parsed.access, InstanceAccess::Public);
+    }
+
+    #[test]
+    fn keeps_unknown_values() {
+        let parsed = round_trip("wrld_a:1~region(usx)");
+        assert_eq!(parsed.region.as_deref(), Some("usx"));
+
+        let parsed = round_trip("wrld_a:1~private(usr_b)~region(eu)~foo(bar)~baz");
+        assert_eq!(parsed.unknown, vec!["foo(bar)", "baz"]);
+
+        let parsed = round_trip("wrld_a:my-room_1");
+        assert_eq!(parsed.instance_name, "my-room_1");
+    }
+
+    #[test]
+    fn rejects_invalid_locations() {
+        for location in [
+            "wrld_a",
+            "wrld_:1",
+            "usr_a:1",
+            "wrld_a:",
+            "wrld_a:~region(eu)",
+            "wrld_a:1~hidden()",
+            "wrld_a:1~group",
+            "wrld_a:1~region()",
+            "wrld_a:1~group(grp_c)~groupAccessType(everyone)",
+            "wrld_a:1~nonce(../x)",
+            "wrld_a:1~foo(bar?baz)",
+            "offline",
+        ] {
+            assert!(Location::parse(location).is_err(), "{}", location);
+        }
+    }
+
+    #[test]
+    fn parses_special_user_locations() {
+        for (location, expected) in [
+            ("offline", UserLocation::Offline),
+            ("private", UserLocation::Private),
+            ("traveling", UserLocation::Traveling),
+        ] {
+            let parsed = UserLocation::parse(location).unwrap();
+            assert_eq!(parsed, expected);
+            assert_eq!(parsed.to_string(), location);
+        }
+
+        assert_eq!(UserLocation::parse("").unwrap(), UserLocation::Offline);
+
+        let parsed = UserLocation::parse("wrld_a:1~friends(usr_b)").unwrap();
+        assert_eq!(parsed.to_string(), "wrld_a:1~friends(usr_b)");
+        assert!(matches!(parsed, UserLocation::Instance(_)));
+    }
+}
@@ -1,2 +1,12 @@
+pub mod avatar;
+pub mod favorite;
+pub mod friend;
+pub mod group;
+pub mod instance;
+pub mod location;
+pub mod moderation;
+pub mod notification;
 pub mod request;
-mod response;
+pub mod response;
+pub mod user;
+pub mod world;
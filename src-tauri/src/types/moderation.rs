@@ -0,0 +1,63 @@
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub enum PlayerModerationType {
+    Block,
+    Mute,
+    Unmute,
+    HideAvatar,
+    ShowAvatar,
+    InteractOn,
+    InteractOff,
+    MuteChat,
+    UnmuteChat,
+    /// Types added after this was written, so one new type doesn't fail a whole sync
+    #[serde(other)]
+    Unknown,
+}
+
+impl fmt::Display for PlayerModerationType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            PlayerModerationType::Block => "block",
+            PlayerModerationType::Mute => "mute",
+            PlayerModerationType::Unmute => "unmute",
+            PlayerModerationType::HideAvatar => "hideAvatar",
+            PlayerModerationType::ShowAvatar => "showAvatar",
+            PlayerModerationType::InteractOn => "interactOn",
+            PlayerModerationType::InteractOff => "interactOff",
+            PlayerModerationType::MuteChat => "muteChat",
+            PlayerModerationType::UnmuteChat => "unmuteChat",
+            PlayerModerationType::Unknown => "unknown",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+/// A moderation the current user applied to another player
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct PlayerModeration {
+    pub id: String,
+    #[serde(rename = "type")]
+    pub moderation_type: PlayerModerationType,
+    #[serde(default)]
+    pub source_user_id: String,
+    #[serde(default)]
+    pub source_display_name: String,
+    pub target_user_id: String,
+    #[serde(default)]
+    pub target_display_name: String,
+    #[serde(default)]
+    pub created: Option<String>,
+}
+
+/// Body for `auth/user/playermoderations` and `auth/user/unplayermoderate`
+#[derive(Serialize, Deserialize, Debug)]
+pub struct ModeratePlayer {
+    pub moderated: String,
+    #[serde(rename = "type")]
+    pub moderation_type: PlayerModerationType,
+}
@@ -0,0 +1,137 @@
+use serde::{Deserialize, Serialize};
+
+/// Number of invite and request message slots VRChat allows per message type
+pub const MESSAGE_SLOT_COUNT: u8 = 12;
+
+pub fn validate_message_slot(slot: u8) -> Result<(), String> {
+    if slot >= MESSAGE_SLOT_COUNT {
+        return Err(format!(
+            "Message slot must be between 0 and {}",
+            MESSAGE_SLOT_COUNT - 1
+        ));
+    }
+    Ok(())
+}
+
+/// A legacy (v1) VRChat notification
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Notification {
+    pub id: String,
+    #[serde(rename = "type")]
+    pub notification_type: String,
+    #[serde(default)]
+    pub sender_user_id: Option<String>,
+    #[serde(default)]
+    pub sender_username: Option<String>,
+    #[serde(default)]
+    pub receiver_user_id: Option<String>,
+    #[serde(default)]
+    pub message: String,
+    #[serde(default)]
+    pub details: serde_json::Value,
+    #[serde(default)]
+    pub seen: bool,
+    #[serde(rename = "created_at", default)]
+    pub created_at: Option<String>,
+}
+
+/// Body for `invite/{userId}`
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct SendInvite {
+    pub instance_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message_slot: Option<u8>,
+}
+
+/// Body for `requestInvite/{userId}`
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct RequestInvite {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub request_slot: Option<u8>,
+}
+
+/// Body for `invite/{notificationId}/response`
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct InviteResponse {
+    pub response_slot: u8,
+}
+
+/// A v2 VRChat notification, used for group announcements, group invites, join requests and system messages
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct NotificationV2 {
+    pub id: String,
+    #[serde(default)]
+    pub version: u32,
+    #[serde(rename = "type")]
+    pub notification_type: String,
+    #[serde(default)]
+    pub category: Option<String>,
+    #[serde(default)]
+    pub is_system: bool,
+    #[serde(rename = "ignoreDND", default)]
+    pub ignore_dnd: bool,
+    #[serde(default)]
+    pub sender_user_id: Option<String>,
+    #[serde(default)]
+    pub sender_username: Option<String>,
+    #[serde(default)]
+    pub receiver_user_id: Option<String>,
+    #[serde(rename = "relatedNotificationsId", default)]
+    pub related_notification_id: Option<String>,
+    #[serde(default)]
+    pub title: String,
+    #[serde(default)]
+    pub message: String,
+    #[serde(default)]
+    pub image_url: Option<String>,
+    #[serde(default)]
+    pub link: Option<String>,
+    #[serde(default)]
+    pub link_text: Option<String>,
+    #[serde(default)]
+    pub responses: Vec<NotificationV2Response>,
+    #[serde(default)]
+    pub expires_at: Option<String>,
+    #[serde(default)]
+    pub expiry_after_seen: Option<u32>,
+    #[serde(default)]
+    pub require_seen: bool,
+    #[serde(default)]
+    pub seen: bool,
+    #[serde(default)]
+    pub can_delete: bool,
+    #[serde(default)]
+    pub created_at: Option<String>,
+    #[serde(default)]
+    pub updated_at: Option<String>,
+    #[serde(default)]
+    pub data: serde_json::Value,
+}
+
+/// A response option offered by a v2 notification, such as accept, decline or block
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct NotificationV2Response {
+    #[serde(rename = "type")]
+    pub response_type: String,
+    #[serde(default)]
+    pub data: String,
+    #[serde(default)]
+    pub icon: Option<String>,
+    #[serde(default)]
+    pub text: Option<String>,
+}
+
+/// Body for `notifications/{notificationId}/respond`
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct NotificationV2RespondBody {
+    pub notification_id: String,
+    pub response_type: String,
+    pub response_data: String,
+}
@@ -8,3 +8,25 @@ pub struct Request {
     pub(crate) headers: Option<HashMap<String, String>>,
     pub(crate) body: Option<serde_json::Value>,
 }
+
+/// A failed request, `status` is only set when VRChat answered with a non success status
+#[derive(Debug)]
+pub struct RequestError {
+    pub status: Option<u16>,
+    pub message: String,
+}
+
+impl From<String> for RequestError {
+    fn from(message: String) -> Self {
+        RequestError {
+            status: None,
+            message,
+        }
+    }
+}
+
+impl From<RequestError> for String {
+    fn from(error: RequestError) -> Self {
+        error.message
+    }
+}
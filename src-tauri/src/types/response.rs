@@ -5,3 +5,16 @@ pub(crate) struct Response {
     pub(crate) data: String,
     pub(crate) status: String,
 }
+
+/// Generic success body returned by VRChat for action endpoints
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct Success {
+    pub success: SuccessMessage,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct SuccessMessage {
+    pub message: String,
+    #[serde(default)]
+    pub status_code: u16,
+}
@@ -0,0 +1,163 @@
+use serde::{Deserialize, Serialize};
+
+// VRChat rejects longer values, these are checked locally before sending an update
+pub const MAX_STATUS_DESCRIPTION_LENGTH: usize = 32;
+pub const MAX_BIO_LENGTH: usize = 512;
+pub const MAX_BIO_LINKS: usize = 3;
+pub const MAX_PRONOUNS_LENGTH: usize = 32;
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UserStatus {
+    #[serde(rename = "join me")]
+    JoinMe,
+    #[serde(rename = "active")]
+    Active,
+    #[serde(rename = "ask me")]
+    AskMe,
+    #[serde(rename = "busy")]
+    Busy,
+    #[serde(rename = "offline")]
+    Offline,
+}
+
+/// The logged in user as returned by `auth/user` and `users/{userId}` updates
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct CurrentUser {
+    pub id: String,
+    pub display_name: String,
+    #[serde(default)]
+    pub status: Option<UserStatus>,
+    #[serde(default)]
+    pub status_description: String,
+    #[serde(default)]
+    pub bio: String,
+    #[serde(default)]
+    pub bio_links: Vec<String>,
+    #[serde(default)]
+    pub pronouns: String,
+    #[serde(default)]
+    pub current_avatar: Option<String>,
+    #[serde(default)]
+    pub current_avatar_thumbnail_image_url: Option<String>,
+    #[serde(default)]
+    pub tags: Vec<String>,
+    /// Ids of every friend, online or not
+    #[serde(default)]
+    pub friends: Vec<String>,
+    /// `18+` once the user has verified their age
+    #[serde(default)]
+    pub age_verification_status: String,
+}
+
+/// Body for `users/{userId}`, fields left as `None` are not changed
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateUser {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<UserStatus>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status_description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bio: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bio_links: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pronouns: Option<String>,
+}
+
+fn check_length(field: &str, value: &Option<String>, max: usize) -> Result<(), String> {
+    match value {
+        Some(value) if value.chars().count() > max => Err(format!(
+            "{} cannot be longer than {} characters",
+            field, max
+        )),
+        _ => Ok(()),
+    }
+}
+
+/// User ids go straight into request paths, so only characters VRChat uses in ids are allowed.
+/// Old accounts have ids without the `usr_` prefix
+pub fn validate_user_id(user_id: &str) -> Result<(), String> {
+    if user_id.is_empty()
+        || !user_id
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-')
+    {
+        return Err(format!("{} is not a user id", user_id));
+    }
+    Ok(())
+}
+
+impl UpdateUser {
+    pub fn validate(&self) -> Result<(), String> {
+        if self.status == Some(UserStatus::Offline) {
+            return Err("Status cannot be set to offline".to_string());
+        }
+
+        check_length(
+            "Status description",
+            &self.status_description,
+            MAX_STATUS_DESCRIPTION_LENGTH,
+        )?;
+        check_length("Bio", &self.bio, MAX_BIO_LENGTH)?;
+        check_length("Pronouns", &self.pronouns, MAX_PRONOUNS_LENGTH)?;
+
+        if let Some(links) = &self.bio_links {
+            if links.len() > MAX_BIO_LINKS {
+                return Err(format!("Bio cannot have more than {} links", MAX_BIO_LINKS));
+            }
+            if let Some(link) = links
+                .iter()
+                .find(|link| !link.starts_with("https://") && !link.starts_with("http://"))
+            {
+                return Err(format!("Bio link {} must start with https://", link));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// A private note the current user keeps about another user
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct UserNote {
+    #[serde(default)]
+    pub id: String,
+    #[serde(default)]
+    pub user_id: String,
+    pub target_user_id: String,
+    #[serde(default)]
+    pub note: String,
+    #[serde(default)]
+    pub created_at: Option<String>,
+}
+
+/// Body for `userNotes`
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateUserNote {
+    pub target_user_id: String,
+    pub note: String,
+}
+
+/// The reduced user model returned by user search and friend lists
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct LimitedUser {
+    pub id: String,
+    pub display_name: String,
+    #[serde(default)]
+    pub status: Option<UserStatus>,
+    #[serde(default)]
+    pub status_description: String,
+    #[serde(default)]
+    pub current_avatar_thumbnail_image_url: Option<String>,
+    #[serde(default)]
+    pub is_friend: bool,
+    #[serde(default)]
+    pub location: Option<String>,
+    #[serde(default)]
+    pub tags: Vec<String>,
+}
@@ -0,0 +1,143 @@
+use serde::{Deserialize, Serialize};
+
+/// A VRChat world, the same model is returned by `worlds/{worldId}` and the world search endpoints
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct World {
+    pub id: String,
+    pub name: String,
+    #[serde(default)]
+    pub description: Option<String>,
+    #[serde(default)]
+    pub author_id: String,
+    #[serde(default)]
+    pub author_name: String,
+    #[serde(default)]
+    pub capacity: u32,
+    #[serde(default)]
+    pub recommended_capacity: u32,
+    #[serde(default)]
+    pub image_url: String,
+    #[serde(default)]
+    pub thumbnail_image_url: String,
+    #[serde(default)]
+    pub release_status: String,
+    #[serde(default)]
+    pub tags: Vec<String>,
+    #[serde(default)]
+    pub favorites: u32,
+    #[serde(default)]
+    pub featured: bool,
+    #[serde(default)]
+    pub heat: u32,
+    #[serde(default)]
+    pub popularity: u32,
+    #[serde(default)]
+    pub occupants: u32,
+    #[serde(default)]
+    pub public_occupants: u32,
+    #[serde(default)]
+    pub private_occupants: u32,
+    #[serde(default)]
+    pub visits: u32,
+    #[serde(rename = "created_at", default)]
+    pub created_at: Option<String>,
+    #[serde(rename = "updated_at", default)]
+    pub updated_at: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub enum WorldSort {
+    Popularity,
+    Heat,
+    Trust,
+    Shuffle,
+    Random,
+    Favorites,
+    PublicationDate,
+    LabsPublicationDate,
+    Created,
+    Updated,
+    Order,
+    Relevance,
+    Magic,
+    Name,
+}
+
+impl WorldSort {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            WorldSort::Popularity => "popularity",
+            WorldSort::Heat => "heat",
+            WorldSort::Trust => "trust",
+            WorldSort::Shuffle => "shuffle",
+            WorldSort::Random => "random",
+            WorldSort::Favorites => "favorites",
+            WorldSort::PublicationDate => "publicationDate",
+            WorldSort::LabsPublicationDate => "labsPublicationDate",
+            WorldSort::Created => "created",
+            WorldSort::Updated => "updated",
+            WorldSort::Order => "order",
+            WorldSort::Relevance => "relevance",
+            WorldSort::Magic => "magic",
+            WorldSort::Name => "name",
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum SortOrder {
+    Ascending,
+    Descending,
+}
+
+impl SortOrder {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SortOrder::Ascending => "ascending",
+            SortOrder::Descending => "descending",
+        }
+    }
+}
+
+/// Parameters for the world search endpoints, every field is optional
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(rename_all = "camelCase", default)]
+pub struct WorldSearch {
+    pub search: Option<String>,
+    pub tags: Vec<String>,
+    pub featured: Option<bool>,
+    pub sort: Option<WorldSort>,
+    pub order: Option<SortOrder>,
+    pub offset: u16,
+    /// Page size, VRChat allows up to 100
+    pub n: Option<u8>,
+}
+
+impl WorldSearch {
+    pub fn query(&self) -> Vec<(&'static str, String)> {
+        let mut query = vec![
+            ("n", self.n.unwrap_or(100).min(100).to_string()),
+            ("offset", self.offset.to_string()),
+        ];
+
+        if let Some(search) = &self.search {
+            query.push(("search", search.clone()));
+        }
+        if !self.tags.is_empty() {
+            query.push(("tag", self.tags.join(",")));
+        }
+        if let Some(featured) = self.featured {
+            query.push(("featured", featured.to_string()));
+        }
+        if let Some(sort) = self.sort {
+            query.push(("sort", sort.as_str().to_string()));
+        }
+        if let Some(order) = self.order {
+            query.push(("order", order.as_str().to_string()));
+        }
+        query
+    }
+}
@@ -0,0 +1,98 @@
+use chrono::{DateTime, Duration, Local};
+use serde::{Deserialize, Serialize};
+use tauri::AppHandle;
+
+use crate::settings::{load_setting, save_setting};
+use crate::types::location::Location;
+use crate::web::vrc_instance::get_instance;
+
+pub(crate) mod poller;
+
+const WATCHES_KEY: &str = "capacityWatches";
+const DEFAULT_WATCH_HOURS: u32 = 2;
+const MAX_WATCH_HOURS: u32 = 24;
+
+/// A full instance being watched for a free spot
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct CapacityWatch {
+    pub location: String,
+    pub world_id: String,
+    #[serde(default)]
+    pub world_name: Option<String>,
+    pub created_at: String,
+    pub expires_at: String,
+}
+
+impl CapacityWatch {
+    pub fn is_expired(&self, now: DateTime<Local>) -> bool {
+        match DateTime::parse_from_rfc3339(&self.expires_at) {
+            Ok(expires_at) => expires_at <= now,
+            Err(_) => true,
+        }
+    }
+}
+
+pub fn load_watches(app: &AppHandle) -> Result<Vec<CapacityWatch>, String> {
+    Ok(load_setting::<Vec<CapacityWatch>>(app, WATCHES_KEY)?.unwrap_or_default())
+}
+
+pub fn save_watches(app: &AppHandle, watches: &[CapacityWatch]) -> Result<(), String> {
+    save_setting(app, WATCHES_KEY, watches)
+}
+
+#[tauri::command]
+pub fn get_capacity_watches(app: AppHandle) -> Result<Vec<CapacityWatch>, String> {
+    load_watches(&app)
+}
+
+/// Starts watching an instance until it has room, closes or the watch expires.
+/// Watching a location again renews its expiry
+#[tauri::command]
+pub async fn add_capacity_watch(
+    app: AppHandle,
+    location: String,
+    hours: Option<u32>,
+) -> Result<CapacityWatch, String> {
+    let parsed = Location::parse(&location)?;
+    let hours = hours.unwrap_or(DEFAULT_WATCH_HOURS);
+    if hours == 0 || hours > MAX_WATCH_HOURS {
+        return Err(format!(
+            "Watches can last between 1 and {} hours",
+            MAX_WATCH_HOURS
+        ));
+    }
+
+    let instance = get_instance(&app, &location).await?;
+    if instance.closed_at.is_some() {
+        return Err(format!("{} is closed", location));
+    }
+
+    let now = Local::now();
+    let watch = CapacityWatch {
+        location: location.clone(),
+        world_id: parsed.world_id,
+        world_name: instance.world.map(|world| world.name),
+        created_at: now.to_rfc3339(),
+        expires_at: (now + Duration::hours(hours as i64)).to_rfc3339(),
+    };
+
+    let mut watches = load_watches(&app)?;
+    watches.retain(|w| w.location != watch.location);
+    watches.push(watch.clone());
+    save_watches(&app, &watches)?;
+
+    Ok(watch)
+}
+
+#[tauri::command]
+pub fn remove_capacity_watch(app: AppHandle, location: String) -> Result<(), String> {
+    let mut watches = load_watches(&app)?;
+    let count = watches.len();
+    watches.retain(|w| w.location != location);
+
+    if watches.len() == count {
+        return Err(format!("{} is not being watched", location));
+    }
+    save_watches(&app, &watches)
+}
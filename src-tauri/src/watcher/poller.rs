@@ -0,0 +1,147 @@
+use chrono::Local;
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+use tauri::AppHandle;
+
+use crate::alerts::dispatch_alert;
+use crate::rules::engine::RuleEvent;
+use crate::types::instance::Instance;
+use crate::types::location::Location;
+use crate::watcher::{load_watches, save_watches, CapacityWatch};
+use crate::web::vrc_instance::find_instance;
+
+const TICK: Duration = Duration::from_secs(10);
+const MIN_INTERVAL: Duration = Duration::from_secs(30);
+const MAX_INTERVAL: Duration = Duration::from_secs(5 * 60);
+
+enum Outcome {
+    Full(Duration),
+    HasRoom { n_users: u32, capacity: u32 },
+    Closed,
+}
+
+/// Instances that are far from having room are checked less often, every player over capacity
+/// or waiting in the queue adds 15 seconds to the interval
+fn next_interval(instance: &Instance) -> Duration {
+    let waiting = instance.n_users.saturating_sub(instance.capacity) + instance.queue_size;
+    (MIN_INTERVAL + Duration::from_secs(15) * waiting).min(MAX_INTERVAL)
+}
+
+async fn check(app: &AppHandle, watch: &CapacityWatch) -> Result<Outcome, String> {
+    Location::parse(&watch.location)?;
+    let instance = match find_instance(app, &watch.location).await? {
+        Some(instance) => instance,
+        None => return Ok(Outcome::Closed),
+    };
+
+    if instance.closed_at.is_some() {
+        Ok(Outcome::Closed)
+    } else if instance.n_users < instance.capacity {
+        Ok(Outcome::HasRoom {
+            n_users: instance.n_users,
+            capacity: instance.capacity,
+        })
+    } else {
+        Ok(Outcome::Full(next_interval(&instance)))
+    }
+}
+
+async fn notify(app: &AppHandle, watch: &CapacityWatch, title: String) {
+    let event = RuleEvent {
+        event_type: "capacity-watch".to_string(),
+        user_id: None,
+        world_id: Some(watch.world_id.clone()),
+        favorite_groups: Vec::new(),
+        time: None,
+    };
+    dispatch_alert(app, &event, &title, &watch.location, Some("Capacity Watch")).await;
+}
+
+/// Polls watched instances and notifies once they have room or close, requests go through the
+/// shared rate limiter so the watcher can't starve the rest of the app
+pub fn start(app: AppHandle) {
+    tauri::async_runtime::spawn(async move {
+        // When each watched location should be checked next and the interval that was used
+        let mut schedule: HashMap<String, (Instant, Duration)> = HashMap::new();
+
+        loop {
+            tokio::time::sleep(TICK).await;
+
+            let watches = match load_watches(&app) {
+                Ok(watches) => watches,
+                Err(e) => {
+                    log::warn!("{}", e);
+                    continue;
+                }
+            };
+
+            let now = Local::now();
+            let mut finished: Vec<String> = watches
+                .iter()
+                .filter(|watch| watch.is_expired(now))
+                .map(|watch| watch.location.clone())
+                .collect();
+
+            for watch in watches.iter().filter(|watch| !watch.is_expired(now)) {
+                let (due, interval) = schedule
+                    .get(&watch.location)
+                    .copied()
+                    .unwrap_or((Instant::now(), MIN_INTERVAL));
+                if due > Instant::now() {
+                    continue;
+                }
+
+                let name = watch.world_name.as_deref().unwrap_or(&watch.world_id);
+                match check(&app, watch).await {
+                    Ok(Outcome::Full(interval)) => {
+                        schedule.insert(
+                            watch.location.clone(),
+                            (Instant::now() + interval, interval),
+                        );
+                    }
+                    Ok(Outcome::HasRoom { n_users, capacity }) => {
+                        let title = format!("{} has room! ({}/{})", name, n_users, capacity);
+                        notify(&app, watch, title).await;
+                        finished.push(watch.location.clone());
+                    }
+                    Ok(Outcome::Closed) => {
+                        notify(
+                            &app,
+                            watch,
+                            format!("The {} instance you were watching closed", name),
+                        )
+                        .await;
+                        finished.push(watch.location.clone());
+                    }
+                    Err(e) => {
+                        log::warn!("Failed to check {}: {}", watch.location, e);
+                        let interval = (interval * 2).min(MAX_INTERVAL);
+                        schedule.insert(
+                            watch.location.clone(),
+                            (Instant::now() + interval, interval),
+                        );
+                    }
+                }
+            }
+
+            schedule.retain(|location, _| watches.iter().any(|w| &w.location == location));
+            if finished.is_empty() {
+                continue;
+            }
+
+            // Reload so watches added while polling are kept
+            match load_watches(&app) {
+                Ok(mut watches) => {
+                    watches.retain(|watch| !finished.contains(&watch.location));
+                    if let Err(e) = save_watches(&app, &watches) {
+                        log::warn!("{}", e);
+                    }
+                }
+                Err(e) => log::warn!("{}", e),
+            }
+            for location in finished {
+                schedule.remove(&location);
+            }
+        }
+    });
+}
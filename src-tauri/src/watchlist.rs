@@ -0,0 +1,466 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+use tauri::{AppHandle, Manager};
+
+use crate::alerts::dispatch_alert;
+use crate::joinability::{evaluate_location, JoinContext};
+use crate::rules::engine::RuleEvent;
+use crate::settings::{load_setting, save_setting};
+
+// Friend events come from the pipeline through the frontend, the watchlist decides which of them
+// are worth an alert and delivers them through the notification rules
+const WATCHLIST_KEY: &str = "friendWatchlist";
+
+#[derive(Default)]
+pub struct WatchlistState(Mutex<WatchlistMemory>);
+
+#[derive(Default)]
+struct WatchlistMemory {
+    /// When each entry last fired
+    fired: HashMap<String, Instant>,
+    /// Last known status of each friend, so only changes to join me fire
+    statuses: HashMap<String, String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub enum WatchTrigger {
+    ComesOnline,
+    GoesOffline,
+    JoinsJoinableInstance,
+    JoinsWorld,
+    StatusJoinMe,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct WatchlistEntry {
+    #[serde(default)]
+    pub id: String,
+    pub user_id: String,
+    pub display_name: String,
+    pub triggers: Vec<WatchTrigger>,
+    /// Worlds for the `joinsWorld` trigger
+    #[serde(default)]
+    pub world_ids: Vec<String>,
+    /// Minimum time between two alerts for this entry
+    #[serde(default = "default_cooldown")]
+    pub cooldown_minutes: u32,
+    #[serde(default = "default_enabled")]
+    pub enabled: bool,
+}
+
+fn default_cooldown() -> u32 {
+    10
+}
+
+fn default_enabled() -> bool {
+    true
+}
+
+/// A friend pipeline event such as `friend-online` or `friend-location`
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct FriendEvent {
+    pub event_type: String,
+    pub user_id: String,
+    #[serde(default)]
+    pub display_name: Option<String>,
+    #[serde(default)]
+    pub location: Option<String>,
+    #[serde(default)]
+    pub world_id: Option<String>,
+    #[serde(default)]
+    pub world_name: Option<String>,
+    #[serde(default)]
+    pub status: Option<String>,
+    /// Favorite groups the friend is in, used by the notification rules
+    #[serde(default)]
+    pub favorite_groups: Vec<String>,
+}
+
+/// An alert sent for a watchlist entry
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct WatchlistAlert {
+    pub entry_id: String,
+    pub trigger: WatchTrigger,
+    pub title: String,
+}
+
+pub fn load_watchlist(app: &AppHandle) -> Result<Vec<WatchlistEntry>, String> {
+    Ok(load_setting::<Vec<WatchlistEntry>>(app, WATCHLIST_KEY)?.unwrap_or_default())
+}
+
+fn save_watchlist(app: &AppHandle, entries: &[WatchlistEntry]) -> Result<(), String> {
+    save_setting(app, WATCHLIST_KEY, entries)
+}
+
+impl WatchlistEntry {
+    pub fn validate(&self) -> Result<(), String> {
+        if !self.user_id.starts_with("usr_") {
+            return Err(format!("{} is not a user id", self.user_id));
+        }
+        if self.triggers.is_empty() {
+            return Err("Pick at least one trigger".to_string());
+        }
+        if self.triggers.contains(&WatchTrigger::JoinsWorld) && self.world_ids.is_empty() {
+            return Err("Pick at least one world to watch".to_string());
+        }
+        Ok(())
+    }
+}
+
+/// Whether the current user can join a location, decided the same way as for friend snapshots
+async fn is_joinable(app: &AppHandle, location: &str) -> bool {
+    match JoinContext::load(app).await {
+        Ok(mut ctx) => evaluate_location(app, location, &mut ctx).await.joinable,
+        Err(e) => {
+            log::warn!("{}", e);
+            false
+        }
+    }
+}
+
+/// Works out which trigger an event fires for an entry, at most one per event. `joinable` is
+/// whether the current user can join the event location
+fn triggered(
+    entry: &WatchlistEntry,
+    event: &FriendEvent,
+    previous_status: Option<&str>,
+    joinable: bool,
+) -> Option<(WatchTrigger, String)> {
+    let name = event.display_name.as_deref().unwrap_or(&entry.display_name);
+    let world = event
+        .world_name
+        .as_deref()
+        .or(event.world_id.as_deref())
+        .unwrap_or("a world");
+
+    entry.triggers.iter().find_map(|trigger| {
+        let fired = match trigger {
+            WatchTrigger::ComesOnline => event.event_type == "friend-online",
+            WatchTrigger::GoesOffline => event.event_type == "friend-offline",
+            WatchTrigger::JoinsJoinableInstance => {
+                event.event_type == "friend-location" && joinable
+            }
+            WatchTrigger::JoinsWorld => {
+                event.event_type == "friend-location"
+                    && event
+                        .world_id
+                        .as_ref()
+                        .is_some_and(|world_id| entry.world_ids.contains(world_id))
+            }
+            // Without a known status the friend may have been on join me all along, which is
+            // only news when they just came online
+            WatchTrigger::StatusJoinMe => {
+                event.status.as_deref() == Some("join me")
+                    && match previous_status {
+                        Some(status) => status != "join me",
+                        None => event.event_type == "friend-online",
+                    }
+            }
+        };
+        if !fired {
+            return None;
+        }
+
+        let title = match trigger {
+            WatchTrigger::ComesOnline => format!("{} is online", name),
+            WatchTrigger::GoesOffline => format!("{} went offline", name),
+            WatchTrigger::JoinsJoinableInstance => format!("{} joined a joinable {}", name, world),
+            WatchTrigger::JoinsWorld => format!("{} joined {}", name, world),
+            WatchTrigger::StatusJoinMe => format!("{} is now join me", name),
+        };
+        Some((*trigger, title))
+    })
+}
+
+#[tauri::command]
+pub fn get_friend_watchlist(app: AppHandle) -> Result<Vec<WatchlistEntry>, String> {
+    load_watchlist(&app)
+}
+
+/// Adds a friend to the watchlist, a new id is assigned to the entry
+#[tauri::command]
+pub fn add_watchlist_entry(
+    app: AppHandle,
+    mut entry: WatchlistEntry,
+) -> Result<WatchlistEntry, String> {
+    entry.validate()?;
+
+    let millis = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_err(|e| format!("Failed to get time: {}", e))?
+        .as_millis();
+    entry.id = format!("watch_{}", millis);
+
+    let mut entries = load_watchlist(&app)?;
+    entries.push(entry.clone());
+    save_watchlist(&app, &entries)?;
+
+    Ok(entry)
+}
+
+#[tauri::command]
+pub fn update_watchlist_entry(
+    app: AppHandle,
+    entry: WatchlistEntry,
+) -> Result<WatchlistEntry, String> {
+    entry.validate()?;
+
+    let mut entries = load_watchlist(&app)?;
+    match entries.iter_mut().find(|e| e.id == entry.id) {
+        Some(existing) => *existing = entry.clone(),
+        None => return Err(format!("Watchlist entry {} does not exist", entry.id)),
+    }
+    save_watchlist(&app, &entries)?;
+
+    Ok(entry)
+}
+
+#[tauri::command]
+pub fn delete_watchlist_entry(app: AppHandle, entry_id: String) -> Result<(), String> {
+    let mut entries = load_watchlist(&app)?;
+    let count = entries.len();
+    entries.retain(|e| e.id != entry_id);
+
+    if entries.len() == count {
+        return Err(format!("Watchlist entry {} does not exist", entry_id));
+    }
+    save_watchlist(&app, &entries)
+}
+
+/// Checks a friend pipeline event against the watchlist and sends alerts for entries that are not cooling down
+#[tauri::command]
+pub async fn evaluate_friend_watchlist(
+    app: AppHandle,
+    event: FriendEvent,
+) -> Result<Vec<WatchlistAlert>, String> {
+    let entries: Vec<WatchlistEntry> = load_watchlist(&app)?
+        .into_iter()
+        .filter(|entry| entry.enabled && entry.user_id == event.user_id)
+        .collect();
+
+    // Joinability needs the instance, so it is only looked up when an entry asks for it
+    let wants_joinability = event.event_type == "friend-location"
+        && entries.iter().any(|entry| {
+            entry
+                .triggers
+                .contains(&WatchTrigger::JoinsJoinableInstance)
+        });
+    let joinable = match &event.location {
+        Some(location) if wants_joinability => is_joinable(&app, location).await,
+        _ => false,
+    };
+
+    let alerts: Vec<WatchlistAlert> = {
+        let state = app.state::<WatchlistState>();
+        let mut memory = state.0.lock().unwrap();
+
+        let previous_status = match &event.status {
+            Some(status) => memory
+                .statuses
+                .insert(event.user_id.clone(), status.clone()),
+            None => memory.statuses.get(&event.user_id).cloned(),
+        };
+
+        let mut alerts = Vec::new();
+        for entry in &entries {
+            let cooldown = Duration::from_secs(entry.cooldown_minutes as u64 * 60);
+            if memory
+                .fired
+                .get(&entry.id)
+                .is_some_and(|fired| fired.elapsed() < cooldown)
+            {
+                continue;
+            }
+
+            if let Some((trigger, title)) =
+                triggered(entry, &event, previous_status.as_deref(), joinable)
+            {
+                memory.fired.insert(entry.id.clone(), Instant::now());
+                alerts.push(WatchlistAlert {
+                    entry_id: entry.id.clone(),
+                    trigger,
+                    title,
+                });
+            }
+        }
+        alerts
+    };
+
+    let rule_event = RuleEvent {
+        event_type: "watchlist".to_string(),
+        user_id: Some(event.user_id.clone()),
+        world_id: event.world_id.clone(),
+        favorite_groups: event.favorite_groups.clone(),
+        time: None,
+    };
+    for alert in &alerts {
+        let message = event.location.as_deref().unwrap_or_default();
+        dispatch_alert(&app, &rule_event, &alert.title, message, Some("Watchlist")).await;
+    }
+
+    Ok(alerts)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(triggers: Vec<WatchTrigger>) -> WatchlistEntry {
+        WatchlistEntry {
+            id: "watch_1".to_string(),
+            user_id: "usr_a".to_string(),
+            display_name: "Saved Name".to_string(),
+            triggers,
+            world_ids: vec!["wrld_b".to_string()],
+            cooldown_minutes: 10,
+            enabled: true,
+        }
+    }
+
+    fn event(event_type: &str) -> FriendEvent {
+        FriendEvent {
+            event_type: event_type.to_string(),
+            user_id: "usr_a".to_string(),
+            display_name: Some("Friend".to_string()),
+            location: None,
+            world_id: None,
+            world_name: None,
+            status: None,
+            favorite_groups: Vec::new(),
+        }
+    }
+
+    fn location_event(world_id: &str) -> FriendEvent {
+        FriendEvent {
+            location: Some(format!("{}:1~hidden(usr_c)", world_id)),
+            world_id: Some(world_id.to_string()),
+            world_name: Some("The World".to_string()),
+            ..event("friend-location")
+        }
+    }
+
+    fn status_event(status: &str) -> FriendEvent {
+        FriendEvent {
+            status: Some(status.to_string()),
+            ..event("friend-update")
+        }
+    }
+
+    fn trigger_of(
+        entry: &WatchlistEntry,
+        event: &FriendEvent,
+        previous_status: Option<&str>,
+        joinable: bool,
+    ) -> Option<WatchTrigger> {
+        triggered(entry, event, previous_status, joinable).map(|(trigger, _)| trigger)
+    }
+
+    #[test]
+    fn online_and_offline() {
+        let watch = entry(vec![WatchTrigger::ComesOnline, WatchTrigger::GoesOffline]);
+
+        assert_eq!(
+            triggered(&watch, &event("friend-online"), None, false),
+            Some((WatchTrigger::ComesOnline, "Friend is online".to_string()))
+        );
+        assert_eq!(
+            trigger_of(&watch, &event("friend-offline"), None, false),
+            Some(WatchTrigger::GoesOffline)
+        );
+        assert_eq!(
+            trigger_of(&watch, &event("friend-active"), None, false),
+            None
+        );
+
+        let unnamed = FriendEvent {
+            display_name: None,
+            ..event("friend-online")
+        };
+        assert_eq!(
+            triggered(&watch, &unnamed, None, false).map(|(_, title)| title),
+            Some("Saved Name is online".to_string())
+        );
+    }
+
+    #[test]
+    fn joinable_instances() {
+        let watch = entry(vec![WatchTrigger::JoinsJoinableInstance]);
+        let moved = location_event("wrld_x");
+
+        assert_eq!(
+            triggered(&watch, &moved, None, true),
+            Some((
+                WatchTrigger::JoinsJoinableInstance,
+                "Friend joined a joinable The World".to_string()
+            ))
+        );
+        assert_eq!(trigger_of(&watch, &moved, None, false), None);
+        assert_eq!(
+            trigger_of(&watch, &event("friend-online"), None, true),
+            None
+        );
+    }
+
+    #[test]
+    fn watched_worlds() {
+        let watch = entry(vec![WatchTrigger::JoinsWorld]);
+
+        assert_eq!(
+            trigger_of(&watch, &location_event("wrld_b"), None, false),
+            Some(WatchTrigger::JoinsWorld)
+        );
+        assert_eq!(
+            trigger_of(&watch, &location_event("wrld_x"), None, true),
+            None
+        );
+    }
+
+    #[test]
+    fn join_me_status() {
+        let watch = entry(vec![WatchTrigger::StatusJoinMe]);
+        let join_me = status_event("join me");
+
+        assert_eq!(trigger_of(&watch, &join_me, None, false), None);
+        assert_eq!(
+            trigger_of(
+                &watch,
+                &FriendEvent {
+                    status: Some("join me".to_string()),
+                    ..event("friend-online")
+                },
+                None,
+                false
+            ),
+            Some(WatchTrigger::StatusJoinMe)
+        );
+        assert_eq!(
+            trigger_of(&watch, &join_me, Some("active"), false),
+            Some(WatchTrigger::StatusJoinMe)
+        );
+        assert_eq!(trigger_of(&watch, &join_me, Some("join me"), false), None);
+        assert_eq!(trigger_of(&watch, &status_event("busy"), None, false), None);
+    }
+
+    #[test]
+    fn first_trigger_wins() {
+        let watch = entry(vec![
+            WatchTrigger::JoinsWorld,
+            WatchTrigger::JoinsJoinableInstance,
+        ]);
+
+        assert_eq!(
+            trigger_of(&watch, &location_event("wrld_b"), None, true),
+            Some(WatchTrigger::JoinsWorld)
+        );
+        assert_eq!(
+            trigger_of(&watch, &location_event("wrld_x"), None, true),
+            Some(WatchTrigger::JoinsJoinableInstance)
+        );
+    }
+}
@@ -0,0 +1,37 @@
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+// Entries older than this are dropped whenever the cache grows past MAX_ENTRIES
+const MAX_AGE: Duration = Duration::from_secs(60 * 60);
+const MAX_ENTRIES: usize = 500;
+
+/// Response bodies of GET requests keyed by url, shared by every cached command. Responses
+/// belong to the logged in account, so the cache is cleared whenever that changes
+#[derive(Default)]
+pub struct ResponseCache(Mutex<HashMap<String, (Instant, String)>>);
+
+impl ResponseCache {
+    /// Gets a cached body if it is younger than `ttl`
+    pub fn get(&self, url: &str, ttl: Duration) -> Option<String> {
+        let entries = self.0.lock().unwrap();
+
+        match entries.get(url) {
+            Some((stored, body)) if stored.elapsed() < ttl => Some(body.clone()),
+            _ => None,
+        }
+    }
+
+    pub fn insert(&self, url: String, body: String) {
+        let mut entries = self.0.lock().unwrap();
+
+        if entries.len() >= MAX_ENTRIES {
+            entries.retain(|_, (stored, _)| stored.elapsed() < MAX_AGE);
+        }
+        entries.insert(url, (Instant::now(), body));
+    }
+
+    pub fn clear(&self) {
+        self.0.lock().unwrap().clear();
+    }
+}
@@ -1,15 +1,33 @@
+use crate::joinability::JoinState;
+use crate::types::user::CurrentUser;
+use crate::web::cache::ResponseCache;
 use crate::web::cookies;
 use crate::web::cookies::clear_login_cookies;
+use crate::web::vrc_player_moderation::start_sync;
 use base64::{engine::general_purpose, Engine as _};
 use std::sync::Arc;
 use tauri::http::header::CONTENT_TYPE;
-use tauri::AppHandle;
+use tauri::{AppHandle, Manager};
 use tauri_plugin_http::reqwest::cookie::Jar;
 use tauri_plugin_http::reqwest::header::{AUTHORIZATION, SET_COOKIE, USER_AGENT};
 use tauri_plugin_http::reqwest::{Client, Url};
 
 // Authentication handlers
 
+/// Starts the background work that needs a logged in user, a login that still needs 2fa
+/// doesn't return the user yet
+fn on_authenticated(app: &AppHandle, body: &str) {
+    let authenticated =
+        serde_json::from_str::<serde_json::Value>(body).is_ok_and(|user| user.get("id").is_some());
+    if authenticated {
+        // Cached responses may belong to another account
+        app.state::<ResponseCache>().clear();
+        app.state::<JoinState>()
+            .set_user(serde_json::from_str::<CurrentUser>(body).ok());
+        start_sync(app.clone());
+    }
+}
+
 #[tauri::command]
 pub async fn get_login(
     app: AppHandle,
@@ -82,6 +100,8 @@ pub async fn get_login(
                             cookies::save_otp_cookies(app.clone(), totp_cookie.clone()).unwrap();
                         }
 
+                        on_authenticated(&app, &text);
+
                         Ok(text) // Return the response body
                     }
                     Err(_) => Err("Failed to get login text!".to_string()),
@@ -296,6 +316,8 @@ pub async fn get_logout(app: AppHandle) -> Result<String, String> {
         Ok(res) => {
             if res.status().is_success() {
                 clear_login_cookies(app.clone()).unwrap();
+                app.state::<ResponseCache>().clear();
+                app.state::<JoinState>().set_user(None);
 
                 match res.text().await {
                     Ok(text) => Ok(text),
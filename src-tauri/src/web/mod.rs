@@ -1,16 +1,25 @@
 pub(crate) mod api_time;
+pub(crate) mod cache;
 pub(crate) mod cookies;
 pub(crate) mod login;
+pub(crate) mod rate_limit;
 
 // vrc modules
+pub(crate) mod vrc_avatar;
 pub(crate) mod vrc_avatar_list;
 pub(crate) mod vrc_current_avatar;
 pub(crate) mod vrc_friends;
 pub(crate) mod vrc_group;
+pub(crate) mod vrc_group_membership;
+pub(crate) mod vrc_group_moderation;
 pub(crate) mod vrc_instance;
+pub(crate) mod vrc_invite;
 pub(crate) mod vrc_notifications;
+pub(crate) mod vrc_player_moderation;
 pub(crate) mod vrc_request;
 pub(crate) mod vrc_user;
+pub(crate) mod vrc_user_notes;
+pub(crate) mod vrc_user_search;
 pub(crate) mod vrc_users;
 pub(crate) mod vrc_world;
 pub(crate) mod vrc_favorites;
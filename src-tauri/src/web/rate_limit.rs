@@ -0,0 +1,45 @@
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+// Token bucket shared by every VRChat API request, allows short bursts (like loading every friend's
+// instance on startup) while keeping sustained traffic low enough to avoid being rate limited
+const BUCKET_SIZE: f64 = 50.0;
+const REFILL_PER_SECOND: f64 = 10.0;
+
+struct Bucket {
+    tokens: f64,
+    last_refill: Instant,
+}
+
+pub struct RateLimiter(Mutex<Bucket>);
+
+impl Default for RateLimiter {
+    fn default() -> Self {
+        RateLimiter(Mutex::new(Bucket {
+            tokens: BUCKET_SIZE,
+            last_refill: Instant::now(),
+        }))
+    }
+}
+
+impl RateLimiter {
+    /// Waits until a request is allowed to be sent
+    pub async fn acquire(&self) {
+        loop {
+            let wait = {
+                let mut bucket = self.0.lock().unwrap();
+                let elapsed = bucket.last_refill.elapsed().as_secs_f64();
+                bucket.tokens = (bucket.tokens + elapsed * REFILL_PER_SECOND).min(BUCKET_SIZE);
+                bucket.last_refill = Instant::now();
+
+                if bucket.tokens >= 1.0 {
+                    bucket.tokens -= 1.0;
+                    return;
+                }
+                Duration::from_secs_f64((1.0 - bucket.tokens) / REFILL_PER_SECOND)
+            };
+
+            tokio::time::sleep(wait).await;
+        }
+    }
+}
@@ -0,0 +1,113 @@
+use crate::types::avatar::{Avatar, AvatarSearch, UpdateAvatar};
+use crate::types::request::Request;
+use crate::types::user::CurrentUser;
+use crate::web::vrc_avatar_list::get_vrc_avatar_list;
+use crate::web::vrc_request::{vrc_get_request, vrc_typed_request};
+use tauri::AppHandle;
+
+const PAGE_SIZE: u16 = 100;
+
+#[tauri::command]
+pub async fn get_vrc_avatar(app: AppHandle, avatar_id: String) -> Result<String, String> {
+    let url = format!("https://api.vrchat.cloud/api/1/avatars/{}", avatar_id);
+
+    let req = Request {
+        url: url.to_string(),
+        method: "GET".to_string(),
+        headers: None,
+        body: None,
+    };
+
+    match vrc_get_request(app, req).await {
+        Ok(response) => Ok(response),
+        Err(e) => Err(format!("Error getting avatar!: {}", e)),
+    }
+}
+
+/// Switches the current user into an avatar
+#[tauri::command]
+pub async fn put_vrc_select_avatar(
+    app: AppHandle,
+    avatar_id: String,
+) -> Result<CurrentUser, String> {
+    let url = format!(
+        "https://api.vrchat.cloud/api/1/avatars/{}/select",
+        avatar_id
+    );
+
+    let req = Request {
+        url,
+        method: "PUT".to_string(),
+        headers: None,
+        body: None,
+    };
+
+    match vrc_typed_request::<CurrentUser>(app, req).await {
+        Ok(response) => Ok(response),
+        Err(e) => Err(format!("Error selecting avatar!: {}", e)),
+    }
+}
+
+/// Updates the name, description, tags or release status of one of the user's avatars
+#[tauri::command]
+pub async fn put_vrc_update_avatar(
+    app: AppHandle,
+    avatar_id: String,
+    update: UpdateAvatar,
+) -> Result<Avatar, String> {
+    if let Some(name) = &update.name {
+        if name.trim().is_empty() {
+            return Err("Avatar name cannot be empty".to_string());
+        }
+    }
+
+    let url = format!("https://api.vrchat.cloud/api/1/avatars/{}", avatar_id);
+
+    let body = serde_json::to_value(&update)
+        .map_err(|e| format!("Failed to serialize avatar update: {}", e))?;
+
+    let req = Request {
+        url,
+        method: "PUT".to_string(),
+        headers: None,
+        body: Some(body),
+    };
+
+    match vrc_typed_request::<Avatar>(app, req).await {
+        Ok(response) => Ok(response),
+        Err(e) => Err(format!("Error updating avatar!: {}", e)),
+    }
+}
+
+/// Pages through every avatar the current user owns
+pub async fn get_all_own_avatars(app: &AppHandle) -> Result<Vec<Avatar>, String> {
+    let mut avatars = Vec::new();
+    let mut offset = 0;
+
+    loop {
+        let text = get_vrc_avatar_list(app.clone(), offset).await?;
+        let page = serde_json::from_str::<Vec<Avatar>>(&text)
+            .map_err(|e| format!("Failed to parse avatar list: {}", e))?;
+        let len = page.len() as u16;
+        avatars.extend(page);
+
+        if len < PAGE_SIZE {
+            return Ok(avatars);
+        }
+        offset += PAGE_SIZE;
+    }
+}
+
+/// Searches the current user's avatars by name, tag and platform support
+#[tauri::command]
+pub async fn search_vrc_own_avatars(
+    app: AppHandle,
+    search: AvatarSearch,
+) -> Result<Vec<Avatar>, String> {
+    let avatars = get_all_own_avatars(&app).await?;
+
+    Ok(avatars
+        .into_iter()
+        .filter(|avatar| search.matches(avatar))
+        .collect())
+}
@@ -1,7 +1,14 @@
+use crate::types::favorite::{
+    AddFavorite, Favorite, FavoriteError, FavoriteGroup, FavoriteGroupSummary, FavoriteLimits,
+    FavoriteType,
+};
 use crate::types::request::Request;
-use crate::web::vrc_request::vrc_get_request;
+use crate::types::response::Success;
+use crate::web::vrc_request::{vrc_get_request, vrc_typed_request};
 use tauri::AppHandle;
 
+const PAGE_SIZE: u16 = 100;
+
 #[tauri::command]
 pub async fn get_vrc_favorites(app: AppHandle) -> Result<String, String> {
     let url = "https://api.vrchat.cloud/api/1/favorites?n=100&type=friend";
@@ -18,3 +25,235 @@ pub async fn get_vrc_favorites(app: AppHandle) -> Result<String, String> {
         Err(e) => Err(format!("Error getting favorites!: {}", e.to_string())),
     }
 }
+
+/// Gets a page of favorites of any type, optionally limited to a single favorite group
+#[tauri::command]
+pub async fn get_vrc_favorite_list(
+    app: AppHandle,
+    favorite_type: FavoriteType,
+    tag: Option<String>,
+    offset: u16,
+) -> Result<Vec<Favorite>, String> {
+    let mut url = format!(
+        "https://api.vrchat.cloud/api/1/favorites?n={}&offset={}&type={}",
+        PAGE_SIZE, offset, favorite_type
+    );
+    if let Some(tag) = tag {
+        url.push_str(&format!("&tag={}", tag));
+    }
+
+    let req = Request {
+        url,
+        method: "GET".to_string(),
+        headers: None,
+        body: None,
+    };
+
+    match vrc_typed_request::<Vec<Favorite>>(app, req).await {
+        Ok(response) => Ok(response),
+        Err(e) => Err(format!("Error getting favorites!: {}", e)),
+    }
+}
+
+/// Pages through every favorite of a type
+pub async fn get_all_favorites(
+    app: &AppHandle,
+    favorite_type: FavoriteType,
+) -> Result<Vec<Favorite>, String> {
+    let mut favorites = Vec::new();
+    let mut offset = 0;
+
+    loop {
+        let page = get_vrc_favorite_list(app.clone(), favorite_type, None, offset).await?;
+        let len = page.len() as u16;
+        favorites.extend(page);
+
+        if len < PAGE_SIZE {
+            return Ok(favorites);
+        }
+        offset += PAGE_SIZE;
+    }
+}
+
+async fn get_favorite_limits(app: &AppHandle) -> Result<FavoriteLimits, String> {
+    let req = Request {
+        url: "https://api.vrchat.cloud/api/1/auth/user/favoritelimits".to_string(),
+        method: "GET".to_string(),
+        headers: None,
+        body: None,
+    };
+
+    match vrc_typed_request::<FavoriteLimits>(app.clone(), req).await {
+        Ok(response) => Ok(response),
+        Err(e) => Err(format!("Error getting favorite limits!: {}", e)),
+    }
+}
+
+pub async fn get_favorite_groups(app: &AppHandle) -> Result<Vec<FavoriteGroup>, String> {
+    let req = Request {
+        url: "https://api.vrchat.cloud/api/1/favorite/groups?n=100".to_string(),
+        method: "GET".to_string(),
+        headers: None,
+        body: None,
+    };
+
+    match vrc_typed_request::<Vec<FavoriteGroup>>(app.clone(), req).await {
+        Ok(response) => Ok(response),
+        Err(e) => Err(format!("Error getting favorite groups!: {}", e)),
+    }
+}
+
+/// Gets the favorite groups of a type with their display names, size and capacity
+#[tauri::command]
+pub async fn get_vrc_favorite_groups(
+    app: AppHandle,
+    favorite_type: FavoriteType,
+) -> Result<Vec<FavoriteGroupSummary>, String> {
+    let limits = get_favorite_limits(&app).await?;
+    let groups = get_favorite_groups(&app).await?;
+    let favorites = get_all_favorites(&app, favorite_type).await?;
+    let capacity = limits.max_favorites_per_group.get(favorite_type);
+
+    Ok(groups
+        .into_iter()
+        .filter(|group| group.favorite_type == favorite_type)
+        .map(|group| {
+            let count = favorites
+                .iter()
+                .filter(|favorite| favorite.tags.contains(&group.name))
+                .count() as u32;
+
+            FavoriteGroupSummary {
+                group,
+                count,
+                capacity,
+            }
+        })
+        .collect())
+}
+
+/// Makes sure a favorite group exists and has room for another favorite
+async fn check_group_capacity(
+    app: &AppHandle,
+    favorite_type: FavoriteType,
+    group: &str,
+) -> Result<(), FavoriteError> {
+    let summaries = get_vrc_favorite_groups(app.clone(), favorite_type).await?;
+
+    match summaries.iter().find(|summary| summary.group.name == group) {
+        Some(summary) if summary.count >= summary.capacity => Err(FavoriteError::GroupFull {
+            group: group.to_string(),
+            capacity: summary.capacity,
+        }),
+        Some(_) => Ok(()),
+        None => Err(FavoriteError::UnknownGroup {
+            group: group.to_string(),
+        }),
+    }
+}
+
+async fn add_favorite(
+    app: &AppHandle,
+    favorite_type: FavoriteType,
+    favorite_id: String,
+    group: String,
+) -> Result<Favorite, FavoriteError> {
+    let body = serde_json::to_value(AddFavorite {
+        favorite_type,
+        favorite_id,
+        tags: vec![group],
+    })
+    .map_err(|e| format!("Failed to serialize favorite: {}", e))?;
+
+    let req = Request {
+        url: "https://api.vrchat.cloud/api/1/favorites".to_string(),
+        method: "POST".to_string(),
+        headers: None,
+        body: Some(body),
+    };
+
+    match vrc_typed_request::<Favorite>(app.clone(), req).await {
+        Ok(response) => Ok(response),
+        Err(e) => Err(format!("Error adding favorite!: {}", e).into()),
+    }
+}
+
+async fn remove_favorite(app: &AppHandle, id: &str) -> Result<Success, FavoriteError> {
+    let req = Request {
+        url: format!("https://api.vrchat.cloud/api/1/favorites/{}", id),
+        method: "DELETE".to_string(),
+        headers: None,
+        body: None,
+    };
+
+    match vrc_typed_request::<Success>(app.clone(), req).await {
+        Ok(response) => Ok(response),
+        Err(e) => Err(format!("Error removing favorite!: {}", e).into()),
+    }
+}
+
+/// Adds a world, friend or avatar to a favorite group, fails with `groupFull` if the group is at capacity
+#[tauri::command]
+pub async fn post_vrc_favorite(
+    app: AppHandle,
+    favorite_type: FavoriteType,
+    favorite_id: String,
+    group: String,
+) -> Result<Favorite, FavoriteError> {
+    check_group_capacity(&app, favorite_type, &group).await?;
+    add_favorite(&app, favorite_type, favorite_id, group).await
+}
+
+/// Moves a favorite to another group of the same type. VRChat doesn't allow favoriting an item
+/// twice, so it is removed first and put back in its old group when adding it fails
+#[tauri::command]
+pub async fn put_vrc_move_favorite(
+    app: AppHandle,
+    favorite: Favorite,
+    group: String,
+) -> Result<Favorite, FavoriteError> {
+    check_group_capacity(&app, favorite.favorite_type, &group).await?;
+    remove_favorite(&app, &favorite.id).await?;
+
+    let error = match add_favorite(
+        &app,
+        favorite.favorite_type,
+        favorite.favorite_id.clone(),
+        group,
+    )
+    .await
+    {
+        Ok(moved) => return Ok(moved),
+        Err(e) => e,
+    };
+
+    let restored = match favorite.tags.first() {
+        Some(original) => add_favorite(
+            &app,
+            favorite.favorite_type,
+            favorite.favorite_id.clone(),
+            original.clone(),
+        )
+        .await
+        .is_ok(),
+        None => false,
+    };
+    if restored {
+        return Err(error);
+    }
+
+    let message = match error {
+        FavoriteError::Request { message } => message,
+        other => format!("{:?}", other),
+    };
+    Err(FavoriteError::Unfavorited {
+        favorite_id: favorite.favorite_id,
+        message,
+    })
+}
+
+/// Removes a favorite by its favorite record id (`fvrt_`)
+#[tauri::command]
+pub async fn delete_vrc_favorite(app: AppHandle, id: String) -> Result<Success, FavoriteError> {
+    remove_favorite(&app, &id).await
+}
@@ -1,7 +1,29 @@
+use crate::confirmation::consume_confirmation_token;
+use crate::db::logs::add_log;
+use crate::joinability::{evaluate_loaded, FriendJoinability, JoinContext};
+use crate::types::friend::FriendStatus;
+use crate::types::location::Location;
+use crate::types::notification::Notification;
 use crate::types::request::Request;
-use crate::web::vrc_request::vrc_get_request;
-use tauri::AppHandle;
+use crate::types::response::Success;
+use crate::web::vrc_request::{vrc_get_request, vrc_typed_request};
+use serde::Deserialize;
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+use tauri::{AppHandle, Emitter};
 
+const JOINABILITY_EVENT: &str = "friends-joinability";
+
+/// The part of a friend needed to decide joinability
+#[derive(Deserialize)]
+struct FriendLocation {
+    id: String,
+    #[serde(default)]
+    location: String,
+}
+
+/// Gets the online friends as VRChat returns them. Joinability is not part of the response, it is
+/// decided in the background and emitted as `friends-joinability` once the instances are loaded
 #[tauri::command]
 pub async fn get_vrc_friends(app: AppHandle) -> Result<String, String> {
     let url = "https://api.vrchat.cloud/api/1/auth/user/friends?offline=false";
@@ -13,8 +35,210 @@ pub async fn get_vrc_friends(app: AppHandle) -> Result<String, String> {
         body: None,
     };
 
-    match vrc_get_request(app, req).await {
-        Ok(response) => Ok(response),
-        Err(e) => Err(format!("Error getting friends!: {}", e.to_string())),
+    let response = vrc_get_request(app.clone(), req)
+        .await
+        .map_err(|e| format!("Error getting friends!: {}", e.to_string()))?;
+
+    // Instances are fetched in the background so they don't hold up the friends list
+    tauri::async_runtime::spawn(emit_friend_joinability(app, response.clone()));
+
+    Ok(response)
+}
+
+/// Decides the joinability of every friend in a friends list response and emits it as
+/// `friends-joinability`
+async fn emit_friend_joinability(app: AppHandle, response: String) {
+    let result = friend_joinability(&app, &response)
+        .await
+        .and_then(|friends| {
+            app.emit(JOINABILITY_EVENT, friends)
+                .map_err(|e| format!("Failed to emit friend joinability: {}", e))
+        });
+
+    if let Err(e) = result {
+        log::warn!("{}", e);
+    }
+}
+
+async fn friend_joinability(
+    app: &AppHandle,
+    response: &str,
+) -> Result<Vec<FriendJoinability>, String> {
+    let friends: Vec<FriendLocation> =
+        serde_json::from_str(response).map_err(|e| format!("Failed to parse friends: {}", e))?;
+
+    let mut ctx = JoinContext::load(app).await?;
+    for friend in &friends {
+        if let Ok(location) = Location::parse(&friend.location) {
+            ctx.load_group(app, &location).await;
+        }
+    }
+    let ctx = Arc::new(ctx);
+
+    // Friends often share an instance, each location is only evaluated once
+    let locations: HashSet<&str> = friends
+        .iter()
+        .map(|friend| friend.location.as_str())
+        .collect();
+    let tasks: Vec<_> = locations
+        .into_iter()
+        .map(|location| {
+            let app = app.clone();
+            let ctx = ctx.clone();
+            let location = location.to_string();
+            tauri::async_runtime::spawn(async move {
+                let joinability = evaluate_loaded(&app, &location, &ctx).await;
+                (location, joinability)
+            })
+        })
+        .collect();
+
+    let mut decided = HashMap::with_capacity(tasks.len());
+    for task in tasks {
+        match task.await {
+            Ok((location, joinability)) => {
+                decided.insert(location, joinability);
+            }
+            Err(e) => log::warn!("Failed to decide friend joinability: {}", e),
+        }
+    }
+
+    Ok(friends
+        .into_iter()
+        .filter_map(|friend| {
+            let joinability = decided.get(&friend.location)?.clone();
+            Some(FriendJoinability {
+                user_id: friend.id,
+                location: friend.location,
+                joinability,
+            })
+        })
+        .collect())
+}
+
+/// Writes the outcome of a friendship action to the log table
+async fn log_friend_action<T>(
+    app: &AppHandle,
+    log_type: &str,
+    user_id: &str,
+    result: &Result<T, String>,
+) {
+    let message = match result {
+        Ok(_) => format!("{} {}", log_type, user_id),
+        Err(e) => format!("{} {} failed: {}", log_type, user_id, e),
+    };
+
+    if let Err(e) = add_log(app, log_type, &message, Some(user_id), None).await {
+        log::warn!("{}", e);
     }
 }
+
+/// Sends a friend request to a user
+#[tauri::command]
+pub async fn post_vrc_friend_request(
+    app: AppHandle,
+    user_id: String,
+) -> Result<Notification, String> {
+    let url = format!(
+        "https://api.vrchat.cloud/api/1/user/{}/friendRequest",
+        user_id
+    );
+
+    let req = Request {
+        url,
+        method: "POST".to_string(),
+        headers: None,
+        body: None,
+    };
+
+    let result = vrc_typed_request::<Notification>(app.clone(), req)
+        .await
+        .map_err(|e| format!("Error sending friend request!: {}", e));
+
+    log_friend_action(&app, "Friend Request Sent", &user_id, &result).await;
+    result
+}
+
+/// Cancels an outgoing friend request, requires a token for the `cancelFriendRequest` action
+#[tauri::command]
+pub async fn delete_vrc_friend_request(
+    app: AppHandle,
+    user_id: String,
+    confirmation: String,
+) -> Result<Success, String> {
+    consume_confirmation_token(&app, &confirmation, "cancelFriendRequest", &user_id)?;
+
+    let url = format!(
+        "https://api.vrchat.cloud/api/1/user/{}/friendRequest",
+        user_id
+    );
+
+    let req = Request {
+        url,
+        method: "DELETE".to_string(),
+        headers: None,
+        body: None,
+    };
+
+    let result = vrc_typed_request::<Success>(app.clone(), req)
+        .await
+        .map_err(|e| format!("Error cancelling friend request!: {}", e));
+
+    log_friend_action(&app, "Friend Request Cancelled", &user_id, &result).await;
+    result
+}
+
+/// Gets whether a user is a friend or has a pending friend request
+#[tauri::command]
+pub async fn get_vrc_friend_status(
+    app: AppHandle,
+    user_id: String,
+) -> Result<FriendStatus, String> {
+    let url = format!(
+        "https://api.vrchat.cloud/api/1/user/{}/friendStatus",
+        user_id
+    );
+
+    let req = Request {
+        url,
+        method: "GET".to_string(),
+        headers: None,
+        body: None,
+    };
+
+    let result = vrc_typed_request::<FriendStatus>(app.clone(), req)
+        .await
+        .map_err(|e| format!("Error getting friend status!: {}", e));
+
+    log_friend_action(&app, "Friend Status", &user_id, &result).await;
+    result
+}
+
+/// Removes a user from the friends list, requires a token for the `unfriend` action
+#[tauri::command]
+pub async fn delete_vrc_friend(
+    app: AppHandle,
+    user_id: String,
+    confirmation: String,
+) -> Result<Success, String> {
+    consume_confirmation_token(&app, &confirmation, "unfriend", &user_id)?;
+
+    let url = format!(
+        "https://api.vrchat.cloud/api/1/auth/user/friends/{}",
+        user_id
+    );
+
+    let req = Request {
+        url,
+        method: "DELETE".to_string(),
+        headers: None,
+        body: None,
+    };
+
+    let result = vrc_typed_request::<Success>(app.clone(), req)
+        .await
+        .map_err(|e| format!("Error removing friend!: {}", e));
+
+    log_friend_action(&app, "Unfriend", &user_id, &result).await;
+    result
+}
@@ -1,5 +1,10 @@
+use crate::types::group::{
+    CalendarEvents, Group, GroupGalleryImage, GroupInstance, GroupMember, GroupPost, GroupPosts,
+    GroupRole,
+};
 use crate::types::request::Request;
-use crate::web::vrc_request::vrc_get_request;
+use crate::web::vrc_request::{vrc_cached_typed_request, vrc_get_request, vrc_typed_request};
+use std::time::Duration;
 use tauri::AppHandle;
 
 #[tauri::command]
@@ -18,3 +23,226 @@ pub async fn get_vrc_group(app: AppHandle, group_id: String) -> Result<String, S
         Err(e) => Err(format!("Error getting group!: {}", e.to_string())),
     }
 }
+
+const PAGE_SIZE: u16 = 100;
+
+/// Gets a group as a typed model, which includes the current user's membership
+pub async fn get_group(app: &AppHandle, group_id: &str) -> Result<Group, String> {
+    let url = format!(
+        "https://api.vrchat.cloud/api/1/groups/{}?includeRoles=true",
+        group_id
+    );
+
+    let req = Request {
+        url,
+        method: "GET".to_string(),
+        headers: None,
+        body: None,
+    };
+
+    match vrc_typed_request::<Group>(app.clone(), req).await {
+        Ok(response) => Ok(response),
+        Err(e) => Err(format!("Error getting group!: {}", e)),
+    }
+}
+
+/// Gets a group through the response cache, for checks that run on every friend event
+pub async fn get_cached_group(
+    app: &AppHandle,
+    group_id: &str,
+    ttl: Duration,
+) -> Result<Group, String> {
+    let url = format!(
+        "https://api.vrchat.cloud/api/1/groups/{}?includeRoles=true",
+        group_id
+    );
+
+    let req = Request {
+        url,
+        method: "GET".to_string(),
+        headers: None,
+        body: None,
+    };
+
+    match vrc_cached_typed_request::<Group>(app.clone(), req, ttl).await {
+        Ok(response) => Ok(response),
+        Err(e) => Err(format!("Error getting group!: {}", e)),
+    }
+}
+
+/// Gets a page of group members, optionally only the members with a role
+#[tauri::command]
+pub async fn get_vrc_group_members(
+    app: AppHandle,
+    group_id: String,
+    offset: u16,
+    role_id: Option<String>,
+) -> Result<Vec<GroupMember>, String> {
+    let mut url = format!(
+        "https://api.vrchat.cloud/api/1/groups/{}/members?n={}&offset={}&sort=joinedAt:desc",
+        group_id, PAGE_SIZE, offset
+    );
+    if let Some(role_id) = role_id {
+        url.push_str(&format!("&roleId={}", role_id));
+    }
+
+    let req = Request {
+        url,
+        method: "GET".to_string(),
+        headers: None,
+        body: None,
+    };
+
+    match vrc_typed_request::<Vec<GroupMember>>(app, req).await {
+        Ok(response) => Ok(response),
+        Err(e) => Err(format!("Error getting group members!: {}", e)),
+    }
+}
+
+#[tauri::command]
+pub async fn get_vrc_group_roles(
+    app: AppHandle,
+    group_id: String,
+) -> Result<Vec<GroupRole>, String> {
+    let url = format!("https://api.vrchat.cloud/api/1/groups/{}/roles", group_id);
+
+    let req = Request {
+        url,
+        method: "GET".to_string(),
+        headers: None,
+        body: None,
+    };
+
+    match vrc_typed_request::<Vec<GroupRole>>(app, req).await {
+        Ok(response) => Ok(response),
+        Err(e) => Err(format!("Error getting group roles!: {}", e)),
+    }
+}
+
+/// Gets the group instances the current user can see
+#[tauri::command]
+pub async fn get_vrc_group_instances(
+    app: AppHandle,
+    group_id: String,
+) -> Result<Vec<GroupInstance>, String> {
+    let url = format!(
+        "https://api.vrchat.cloud/api/1/groups/{}/instances",
+        group_id
+    );
+
+    let req = Request {
+        url,
+        method: "GET".to_string(),
+        headers: None,
+        body: None,
+    };
+
+    match vrc_typed_request::<Vec<GroupInstance>>(app, req).await {
+        Ok(response) => Ok(response),
+        Err(e) => Err(format!("Error getting group instances!: {}", e)),
+    }
+}
+
+#[tauri::command]
+pub async fn get_vrc_group_posts(
+    app: AppHandle,
+    group_id: String,
+    offset: u16,
+) -> Result<Vec<GroupPost>, String> {
+    let url = format!(
+        "https://api.vrchat.cloud/api/1/groups/{}/posts?n={}&offset={}&publicOnly=false",
+        group_id, PAGE_SIZE, offset
+    );
+
+    let req = Request {
+        url,
+        method: "GET".to_string(),
+        headers: None,
+        body: None,
+    };
+
+    match vrc_typed_request::<GroupPosts>(app, req).await {
+        Ok(response) => Ok(response.posts),
+        Err(e) => Err(format!("Error getting group posts!: {}", e)),
+    }
+}
+
+/// Gets the group's current announcement, groups without one return an empty object
+#[tauri::command]
+pub async fn get_vrc_group_announcement(
+    app: AppHandle,
+    group_id: String,
+) -> Result<Option<GroupPost>, String> {
+    let url = format!(
+        "https://api.vrchat.cloud/api/1/groups/{}/announcement",
+        group_id
+    );
+
+    let req = Request {
+        url,
+        method: "GET".to_string(),
+        headers: None,
+        body: None,
+    };
+
+    let response = vrc_typed_request::<serde_json::Value>(app, req)
+        .await
+        .map_err(|e| format!("Error getting group announcement!: {}", e))?;
+
+    if response.get("id").map_or(true, |id| id.is_null()) {
+        return Ok(None);
+    }
+
+    serde_json::from_value(response)
+        .map(Some)
+        .map_err(|e| format!("Failed to parse group announcement: {}", e))
+}
+
+#[tauri::command]
+pub async fn get_vrc_group_gallery(
+    app: AppHandle,
+    group_id: String,
+    gallery_id: String,
+    offset: u16,
+) -> Result<Vec<GroupGalleryImage>, String> {
+    let url = format!(
+        "https://api.vrchat.cloud/api/1/groups/{}/galleries/{}?n={}&offset={}",
+        group_id, gallery_id, PAGE_SIZE, offset
+    );
+
+    let req = Request {
+        url,
+        method: "GET".to_string(),
+        headers: None,
+        body: None,
+    };
+
+    match vrc_typed_request::<Vec<GroupGalleryImage>>(app, req).await {
+        Ok(response) => Ok(response),
+        Err(e) => Err(format!("Error getting group gallery!: {}", e)),
+    }
+}
+
+#[tauri::command]
+pub async fn get_vrc_group_events(
+    app: AppHandle,
+    group_id: String,
+    offset: u16,
+) -> Result<CalendarEvents, String> {
+    let url = format!(
+        "https://api.vrchat.cloud/api/1/calendar/{}?n={}&offset={}",
+        group_id, PAGE_SIZE, offset
+    );
+
+    let req = Request {
+        url,
+        method: "GET".to_string(),
+        headers: None,
+        body: None,
+    };
+
+    match vrc_typed_request::<CalendarEvents>(app, req).await {
+        Ok(response) => Ok(response),
+        Err(e) => Err(format!("Error getting group events!: {}", e)),
+    }
+}
@@ -0,0 +1,229 @@
+use crate::types::group::{
+    Group, GroupMemberVisibility, GroupMyMember, GroupRepresentation, UpdateGroupMember,
+};
+use crate::types::request::Request;
+use crate::types::response::Success;
+use crate::web::vrc_group::get_group;
+use crate::web::vrc_request::{vrc_get_request, vrc_typed_request};
+use crate::web::vrc_user::get_current_user;
+use tauri::AppHandle;
+
+fn expect_status(group: &Group, expected: &str, action: &str) -> Result<(), String> {
+    let status = group.membership_status();
+    if status != expected {
+        return Err(format!(
+            "Can't {} {}, membership status is {}",
+            action, group.name, status
+        ));
+    }
+    Ok(())
+}
+
+fn expect_member(group: &Group, action: &str) -> Result<(), String> {
+    expect_status(group, "member", action)
+}
+
+async fn join_group(app: &AppHandle, group_id: &str) -> Result<GroupMyMember, String> {
+    let req = Request {
+        url: format!("https://api.vrchat.cloud/api/1/groups/{}/join", group_id),
+        method: "POST".to_string(),
+        headers: None,
+        body: None,
+    };
+
+    vrc_typed_request::<GroupMyMember>(app.clone(), req).await
+}
+
+/// Joins an open group
+#[tauri::command]
+pub async fn post_vrc_join_group(
+    app: AppHandle,
+    group_id: String,
+) -> Result<GroupMyMember, String> {
+    let group = get_group(&app, &group_id).await?;
+    if group.is_member() {
+        return Err(format!("Already a member of {}", group.name));
+    }
+    if group.join_state != "open" {
+        return Err(format!(
+            "{} can't be joined directly, its join state is {}",
+            group.name, group.join_state
+        ));
+    }
+
+    join_group(&app, &group_id)
+        .await
+        .map_err(|e| format!("Error joining group!: {}", e))
+}
+
+/// Requests to join a group that requires approval
+#[tauri::command]
+pub async fn post_vrc_request_join_group(
+    app: AppHandle,
+    group_id: String,
+) -> Result<GroupMyMember, String> {
+    let group = get_group(&app, &group_id).await?;
+    expect_status(&group, "inactive", "request to join")?;
+    if group.join_state != "request" {
+        return Err(format!(
+            "{} doesn't take join requests, its join state is {}",
+            group.name, group.join_state
+        ));
+    }
+
+    join_group(&app, &group_id)
+        .await
+        .map_err(|e| format!("Error requesting to join group!: {}", e))
+}
+
+#[tauri::command]
+pub async fn delete_vrc_group_join_request(app: AppHandle, group_id: String) -> Result<(), String> {
+    let group = get_group(&app, &group_id).await?;
+    expect_status(&group, "requested", "cancel the join request for")?;
+
+    let req = Request {
+        url: format!(
+            "https://api.vrchat.cloud/api/1/groups/{}/requests",
+            group_id
+        ),
+        method: "DELETE".to_string(),
+        headers: None,
+        body: None,
+    };
+
+    vrc_get_request(app, req)
+        .await
+        .map(|_| ())
+        .map_err(|e| format!("Error cancelling join request!: {}", e))
+}
+
+/// Leaves a group, owners have to transfer ownership first
+#[tauri::command]
+pub async fn post_vrc_leave_group(app: AppHandle, group_id: String) -> Result<(), String> {
+    let group = get_group(&app, &group_id).await?;
+    expect_member(&group, "leave")?;
+    if let Some(member) = &group.my_member {
+        if member.user_id == group.owner_id {
+            return Err(format!("Can't leave {}, you own it", group.name));
+        }
+    }
+
+    let req = Request {
+        url: format!("https://api.vrchat.cloud/api/1/groups/{}/leave", group_id),
+        method: "POST".to_string(),
+        headers: None,
+        body: None,
+    };
+
+    vrc_get_request(app, req)
+        .await
+        .map(|_| ())
+        .map_err(|e| format!("Error leaving group!: {}", e))
+}
+
+/// Sets or clears the group the current user represents, only one group can be represented at a time
+#[tauri::command]
+pub async fn put_vrc_represent_group(
+    app: AppHandle,
+    group_id: String,
+    is_representing: bool,
+) -> Result<Success, String> {
+    let group = get_group(&app, &group_id).await?;
+    expect_member(&group, "represent")?;
+
+    let body = serde_json::to_value(GroupRepresentation { is_representing })
+        .map_err(|e| format!("Failed to serialize representation: {}", e))?;
+
+    let req = Request {
+        url: format!(
+            "https://api.vrchat.cloud/api/1/groups/{}/representation",
+            group_id
+        ),
+        method: "PUT".to_string(),
+        headers: None,
+        body: Some(body),
+    };
+
+    match vrc_typed_request::<Success>(app, req).await {
+        Ok(response) => Ok(response),
+        Err(e) => Err(format!("Error setting represented group!: {}", e)),
+    }
+}
+
+/// Updates the current user's announcement subscription and visibility in a group
+#[tauri::command]
+pub async fn put_vrc_update_group_membership(
+    app: AppHandle,
+    group_id: String,
+    visibility: Option<GroupMemberVisibility>,
+    is_subscribed_to_announcements: Option<bool>,
+) -> Result<GroupMyMember, String> {
+    let group = get_group(&app, &group_id).await?;
+    expect_member(&group, "update membership of")?;
+
+    let user_id = match &group.my_member {
+        Some(member) => member.user_id.clone(),
+        None => get_current_user(&app).await?.id,
+    };
+
+    let body = serde_json::to_value(UpdateGroupMember {
+        visibility,
+        is_subscribed_to_announcements,
+    })
+    .map_err(|e| format!("Failed to serialize membership: {}", e))?;
+
+    let req = Request {
+        url: format!(
+            "https://api.vrchat.cloud/api/1/groups/{}/members/{}",
+            group_id, user_id
+        ),
+        method: "PUT".to_string(),
+        headers: None,
+        body: Some(body),
+    };
+
+    match vrc_typed_request::<GroupMyMember>(app, req).await {
+        Ok(response) => Ok(response),
+        Err(e) => Err(format!("Error updating group membership!: {}", e)),
+    }
+}
+
+/// Accepts a pending group invite, which joins the group
+#[tauri::command]
+pub async fn post_vrc_accept_group_invite(
+    app: AppHandle,
+    group_id: String,
+) -> Result<GroupMyMember, String> {
+    let group = get_group(&app, &group_id).await?;
+    expect_status(&group, "invited", "accept an invite to")?;
+
+    join_group(&app, &group_id)
+        .await
+        .map_err(|e| format!("Error accepting group invite!: {}", e))
+}
+
+#[tauri::command]
+pub async fn delete_vrc_decline_group_invite(
+    app: AppHandle,
+    group_id: String,
+) -> Result<(), String> {
+    let group = get_group(&app, &group_id).await?;
+    expect_status(&group, "invited", "decline an invite to")?;
+
+    let user_id = get_current_user(&app).await?.id;
+
+    let req = Request {
+        url: format!(
+            "https://api.vrchat.cloud/api/1/groups/{}/invites/{}",
+            group_id, user_id
+        ),
+        method: "DELETE".to_string(),
+        headers: None,
+        body: None,
+    };
+
+    vrc_get_request(app, req)
+        .await
+        .map(|_| ())
+        .map_err(|e| format!("Error declining group invite!: {}", e))
+}
@@ -0,0 +1,294 @@
+use crate::confirmation::consume_confirmation_token;
+use crate::db::group_moderation::record_group_action;
+use crate::types::group::{
+    BanGroupMember, GroupAuditLogFilter, GroupAuditLogs, GroupMember, JoinRequestAction,
+    RespondGroupJoinRequest,
+};
+use crate::types::request::Request;
+use crate::web::vrc_group::get_group;
+use crate::web::vrc_request::{vrc_get_request, vrc_typed_request};
+use tauri::AppHandle;
+use tauri_plugin_http::reqwest::Url;
+
+/// Confirmation tokens for group actions target `{groupId}/{userId}`
+fn confirmation_target(group_id: &str, user_id: &str) -> String {
+    format!("{}/{}", group_id, user_id)
+}
+
+async fn record(
+    app: &AppHandle,
+    group_id: &str,
+    action: &str,
+    user_id: &str,
+    details: Option<&str>,
+) {
+    if let Err(e) = record_group_action(app, group_id, action, Some(user_id), details).await {
+        log::warn!("{}", e);
+    }
+}
+
+/// Gets a page of pending join requests
+#[tauri::command]
+pub async fn get_vrc_group_join_requests(
+    app: AppHandle,
+    group_id: String,
+    offset: u16,
+) -> Result<Vec<GroupMember>, String> {
+    get_group(&app, &group_id)
+        .await?
+        .require_permission("group-invites-manage")?;
+
+    let req = Request {
+        url: format!(
+            "https://api.vrchat.cloud/api/1/groups/{}/requests?n=100&offset={}",
+            group_id, offset
+        ),
+        method: "GET".to_string(),
+        headers: None,
+        body: None,
+    };
+
+    match vrc_typed_request::<Vec<GroupMember>>(app, req).await {
+        Ok(response) => Ok(response),
+        Err(e) => Err(format!("Error getting join requests!: {}", e)),
+    }
+}
+
+/// Accepts or rejects a join request, rejected users can also be blocked from requesting again
+#[tauri::command]
+pub async fn put_vrc_respond_group_join_request(
+    app: AppHandle,
+    group_id: String,
+    user_id: String,
+    action: JoinRequestAction,
+    block: bool,
+) -> Result<(), String> {
+    get_group(&app, &group_id)
+        .await?
+        .require_permission("group-invites-manage")?;
+
+    let block = block && action == JoinRequestAction::Reject;
+    let body = serde_json::to_value(RespondGroupJoinRequest { action, block })
+        .map_err(|e| format!("Failed to serialize join request response: {}", e))?;
+
+    let req = Request {
+        url: format!(
+            "https://api.vrchat.cloud/api/1/groups/{}/requests/{}",
+            group_id, user_id
+        ),
+        method: "PUT".to_string(),
+        headers: None,
+        body: Some(body),
+    };
+
+    vrc_get_request(app.clone(), req)
+        .await
+        .map_err(|e| format!("Error responding to join request!: {}", e))?;
+
+    let (log_action, details) = match action {
+        JoinRequestAction::Accept => ("acceptJoinRequest", None),
+        JoinRequestAction::Reject if block => ("rejectJoinRequest", Some("blocked")),
+        JoinRequestAction::Reject => ("rejectJoinRequest", None),
+    };
+    record(&app, &group_id, log_action, &user_id, details).await;
+
+    Ok(())
+}
+
+/// Gets a page of the group audit log
+#[tauri::command]
+pub async fn get_vrc_group_audit_logs(
+    app: AppHandle,
+    group_id: String,
+    filter: GroupAuditLogFilter,
+) -> Result<GroupAuditLogs, String> {
+    get_group(&app, &group_id)
+        .await?
+        .require_permission("group-audit-view")?;
+
+    let url = Url::parse_with_params(
+        &format!(
+            "https://api.vrchat.cloud/api/1/groups/{}/auditLogs",
+            group_id
+        ),
+        filter.query(),
+    )
+    .map_err(|e| format!("Failed to build audit log url: {}", e))?;
+
+    let req = Request {
+        url: url.to_string(),
+        method: "GET".to_string(),
+        headers: None,
+        body: None,
+    };
+
+    match vrc_typed_request::<GroupAuditLogs>(app, req).await {
+        Ok(response) => Ok(response),
+        Err(e) => Err(format!("Error getting audit log!: {}", e)),
+    }
+}
+
+/// Bans a user from the group, needs a `groupBan` confirmation token
+#[tauri::command]
+pub async fn post_vrc_group_ban(
+    app: AppHandle,
+    group_id: String,
+    user_id: String,
+    confirmation: String,
+) -> Result<GroupMember, String> {
+    // Checked first so a missing permission doesn't use up the confirmation
+    get_group(&app, &group_id)
+        .await?
+        .require_permission("group-bans-manage")?;
+    consume_confirmation_token(
+        &app,
+        &confirmation,
+        "groupBan",
+        &confirmation_target(&group_id, &user_id),
+    )?;
+
+    let body = serde_json::to_value(BanGroupMember {
+        user_id: user_id.clone(),
+    })
+    .map_err(|e| format!("Failed to serialize ban: {}", e))?;
+
+    let req = Request {
+        url: format!("https://api.vrchat.cloud/api/1/groups/{}/bans", group_id),
+        method: "POST".to_string(),
+        headers: None,
+        body: Some(body),
+    };
+
+    let response = vrc_typed_request::<GroupMember>(app.clone(), req)
+        .await
+        .map_err(|e| format!("Error banning member!: {}", e))?;
+
+    record(&app, &group_id, "ban", &user_id, None).await;
+
+    Ok(response)
+}
+
+#[tauri::command]
+pub async fn delete_vrc_group_ban(
+    app: AppHandle,
+    group_id: String,
+    user_id: String,
+) -> Result<GroupMember, String> {
+    get_group(&app, &group_id)
+        .await?
+        .require_permission("group-bans-manage")?;
+
+    let req = Request {
+        url: format!(
+            "https://api.vrchat.cloud/api/1/groups/{}/bans/{}",
+            group_id, user_id
+        ),
+        method: "DELETE".to_string(),
+        headers: None,
+        body: None,
+    };
+
+    let response = vrc_typed_request::<GroupMember>(app.clone(), req)
+        .await
+        .map_err(|e| format!("Error unbanning member!: {}", e))?;
+
+    record(&app, &group_id, "unban", &user_id, None).await;
+
+    Ok(response)
+}
+
+/// Kicks a member from the group, needs a `groupKick` confirmation token
+#[tauri::command]
+pub async fn delete_vrc_group_member(
+    app: AppHandle,
+    group_id: String,
+    user_id: String,
+    confirmation: String,
+) -> Result<(), String> {
+    get_group(&app, &group_id)
+        .await?
+        .require_permission("group-members-remove")?;
+    consume_confirmation_token(
+        &app,
+        &confirmation,
+        "groupKick",
+        &confirmation_target(&group_id, &user_id),
+    )?;
+
+    let req = Request {
+        url: format!(
+            "https://api.vrchat.cloud/api/1/groups/{}/members/{}",
+            group_id, user_id
+        ),
+        method: "DELETE".to_string(),
+        headers: None,
+        body: None,
+    };
+
+    vrc_get_request(app.clone(), req)
+        .await
+        .map_err(|e| format!("Error kicking member!: {}", e))?;
+
+    record(&app, &group_id, "kick", &user_id, None).await;
+
+    Ok(())
+}
+
+/// Assigns or removes a role, returns the member's role ids afterwards
+async fn set_member_role(
+    app: &AppHandle,
+    group_id: &str,
+    user_id: &str,
+    role_id: &str,
+    assign: bool,
+) -> Result<Vec<String>, String> {
+    let group = get_group(app, group_id).await?;
+    group.require_permission("group-roles-assign")?;
+
+    if let Some(roles) = &group.roles {
+        if !roles.iter().any(|role| role.id == role_id) {
+            return Err(format!("{} has no role {}", group.name, role_id));
+        }
+    }
+
+    let req = Request {
+        url: format!(
+            "https://api.vrchat.cloud/api/1/groups/{}/members/{}/roles/{}",
+            group_id, user_id, role_id
+        ),
+        method: if assign { "PUT" } else { "DELETE" }.to_string(),
+        headers: None,
+        body: None,
+    };
+
+    let response = vrc_typed_request::<Vec<String>>(app.clone(), req).await?;
+
+    let action = if assign { "assignRole" } else { "removeRole" };
+    record(app, group_id, action, user_id, Some(role_id)).await;
+
+    Ok(response)
+}
+
+#[tauri::command]
+pub async fn put_vrc_group_member_role(
+    app: AppHandle,
+    group_id: String,
+    user_id: String,
+    role_id: String,
+) -> Result<Vec<String>, String> {
+    set_member_role(&app, &group_id, &user_id, &role_id, true)
+        .await
+        .map_err(|e| format!("Error assigning role!: {}", e))
+}
+
+#[tauri::command]
+pub async fn delete_vrc_group_member_role(
+    app: AppHandle,
+    group_id: String,
+    user_id: String,
+    role_id: String,
+) -> Result<Vec<String>, String> {
+    set_member_role(&app, &group_id, &user_id, &role_id, false)
+        .await
+        .map_err(|e| format!("Error removing role!: {}", e))
+}
@@ -1,9 +1,26 @@
+use crate::confirmation::consume_confirmation_token;
+use crate::db::logs::add_log;
+use crate::types::group::GroupMember;
+use crate::types::instance::{
+    CreateInstance, CreateInstanceBody, CreatedInstance, Instance, InstanceShortName,
+};
+use crate::types::location::Location;
 use crate::types::request::Request;
-use crate::web::vrc_request::vrc_get_request;
+use crate::types::user::LimitedUser;
+use crate::web::vrc_group::get_group;
+use crate::web::vrc_group_moderation::post_vrc_group_ban;
+use crate::web::vrc_request::{
+    vrc_cached_typed_request, vrc_get_request, vrc_request, vrc_typed_request,
+};
+use crate::web::vrc_user::get_current_user;
+use chrono::DateTime;
+use std::time::Duration;
 use tauri::AppHandle;
+use tauri_plugin_http::reqwest::Url;
 
 #[tauri::command]
 pub async fn get_vrc_instance(app: AppHandle, instance_id: String) -> Result<String, String> {
+    Location::parse(&instance_id)?;
     let url = format!("https://api.vrchat.cloud/api/1/instances/{}", instance_id);
 
     let req = Request {
@@ -18,3 +35,244 @@ pub async fn get_vrc_instance(app: AppHandle, instance_id: String) -> Result<Str
         Err(e) => Err(format!("Error getting instance!: {}", e.to_string())),
     }
 }
+
+/// Gets the short name and secure name of an instance, only available to users who can join it
+pub async fn get_instance_short_name(
+    app: &AppHandle,
+    location: &str,
+) -> Result<InstanceShortName, String> {
+    let url = format!(
+        "https://api.vrchat.cloud/api/1/instances/{}/shortName",
+        location
+    );
+
+    let req = Request {
+        url,
+        method: "GET".to_string(),
+        headers: None,
+        body: None,
+    };
+
+    match vrc_typed_request::<InstanceShortName>(app.clone(), req).await {
+        Ok(response) => Ok(response),
+        Err(e) => Err(format!("Error getting instance short name!: {}", e)),
+    }
+}
+
+/// Creates a new instance and returns its location along with a shareable link when one is available
+#[tauri::command]
+pub async fn post_vrc_create_instance(
+    app: AppHandle,
+    create: CreateInstance,
+) -> Result<CreatedInstance, String> {
+    create.validate()?;
+
+    let owner_id = match &create.owner_id {
+        Some(owner_id) => owner_id.clone(),
+        None => get_current_user(&app).await?.id,
+    };
+
+    let body = serde_json::to_value(CreateInstanceBody::new(create, owner_id))
+        .map_err(|e| format!("Failed to serialize instance: {}", e))?;
+
+    let req = Request {
+        url: "https://api.vrchat.cloud/api/1/instances".to_string(),
+        method: "POST".to_string(),
+        headers: None,
+        body: Some(body),
+    };
+
+    let instance = vrc_typed_request::<Instance>(app.clone(), req)
+        .await
+        .map_err(|e| format!("Error creating instance!: {}", e))?;
+
+    let location = instance.location.clone();
+
+    let (short_name, secure_name) = match (&instance.short_name, &instance.secure_name) {
+        (None, None) => match get_instance_short_name(&app, &location).await {
+            Ok(names) => (names.short_name, names.secure_name),
+            Err(e) => {
+                log::warn!("{}", e);
+                (None, None)
+            }
+        },
+        (short_name, secure_name) => (short_name.clone(), secure_name.clone()),
+    };
+
+    let launch_url = match (&short_name, &secure_name) {
+        (Some(short_name), _) => Some(format!("https://vrch.at/{}", short_name)),
+        (None, Some(secure_name)) => Some(format!(
+            "vrchat://launch?ref=vrchat.com&id={}&shortName={}",
+            location, secure_name
+        )),
+        (None, None) => None,
+    };
+
+    Ok(CreatedInstance {
+        location,
+        short_name,
+        launch_url,
+        instance,
+    })
+}
+
+/// Gets an instance as a typed model, `location` is sent as given so it has to be validated first
+pub async fn get_instance(app: &AppHandle, location: &str) -> Result<Instance, String> {
+    let req = Request {
+        url: format!("https://api.vrchat.cloud/api/1/instances/{}", location),
+        method: "GET".to_string(),
+        headers: None,
+        body: None,
+    };
+
+    match vrc_typed_request::<Instance>(app.clone(), req).await {
+        Ok(response) => Ok(response),
+        Err(e) => Err(format!("Error getting instance!: {}", e)),
+    }
+}
+
+/// Gets an instance like `get_instance`, but returns `None` once VRChat no longer knows about it.
+/// Instances are removed from the API some time after closing
+pub async fn find_instance(app: &AppHandle, location: &str) -> Result<Option<Instance>, String> {
+    let req = Request {
+        url: format!("https://api.vrchat.cloud/api/1/instances/{}", location),
+        method: "GET".to_string(),
+        headers: None,
+        body: None,
+    };
+
+    match vrc_request(app.clone(), req).await {
+        Ok(text) => serde_json::from_str::<Instance>(&text)
+            .map(Some)
+            .map_err(|e| format!("Failed to parse response: {}", e)),
+        Err(e) if e.status == Some(404) => Ok(None),
+        Err(e) => Err(format!("Error getting instance!: {}", e.message)),
+    }
+}
+
+/// Gets an instance through the response cache, for views that show many instances at once
+pub async fn get_cached_instance(
+    app: &AppHandle,
+    location: &str,
+    ttl: Duration,
+) -> Result<Instance, String> {
+    let req = Request {
+        url: format!("https://api.vrchat.cloud/api/1/instances/{}", location),
+        method: "GET".to_string(),
+        headers: None,
+        body: None,
+    };
+
+    match vrc_cached_typed_request::<Instance>(app.clone(), req, ttl).await {
+        Ok(response) => Ok(response),
+        Err(e) => Err(format!("Error getting instance!: {}", e)),
+    }
+}
+
+/// Checks that the current user owns the instance, group instances need the group permission instead
+async fn check_owner(app: &AppHandle, location: &Location, permission: &str) -> Result<(), String> {
+    if let Some(group_id) = &location.group_id {
+        return get_group(app, group_id)
+            .await?
+            .require_permission(permission);
+    }
+
+    let owner_id = location
+        .owner_id
+        .as_deref()
+        .ok_or_else(|| format!("{} has no owner", location))?;
+    if get_current_user(app).await?.id != owner_id {
+        return Err(format!("You don't own {}", location));
+    }
+
+    Ok(())
+}
+
+/// Closes an instance, needs a `closeInstance` confirmation token for the location.
+/// With `hard_close` everyone is removed right away, otherwise the instance only stops taking new players.
+/// `closed_at` schedules the close for a later RFC 3339 time
+#[tauri::command]
+pub async fn delete_vrc_close_instance(
+    app: AppHandle,
+    location: String,
+    hard_close: bool,
+    closed_at: Option<String>,
+    confirmation: String,
+) -> Result<Instance, String> {
+    let parsed = Location::parse(&location)?;
+    check_owner(&app, &parsed, "group-instance-manage").await?;
+    consume_confirmation_token(&app, &confirmation, "closeInstance", &location)?;
+
+    let mut query = vec![("hardClose", hard_close.to_string())];
+    if let Some(closed_at) = closed_at {
+        DateTime::parse_from_rfc3339(&closed_at)
+            .map_err(|e| format!("Invalid close time {}: {}", closed_at, e))?;
+        query.push(("closedAt", closed_at));
+    }
+
+    let url = Url::parse_with_params(
+        &format!("https://api.vrchat.cloud/api/1/instances/{}", location),
+        query,
+    )
+    .map_err(|e| format!("Failed to build instance url: {}", e))?;
+
+    let req = Request {
+        url: url.to_string(),
+        method: "DELETE".to_string(),
+        headers: None,
+        body: None,
+    };
+
+    let response = vrc_typed_request::<Instance>(app.clone(), req)
+        .await
+        .map_err(|e| format!("Error closing instance!: {}", e))?;
+
+    if let Err(e) = add_log(
+        &app,
+        "Instance Closed",
+        &response.name,
+        None,
+        Some(&location),
+    )
+    .await
+    {
+        log::warn!("{}", e);
+    }
+
+    Ok(response)
+}
+
+/// Gets the players in an instance, VRChat only includes them for the owner and group moderators
+#[tauri::command]
+pub async fn get_vrc_instance_players(
+    app: AppHandle,
+    location: String,
+) -> Result<Vec<LimitedUser>, String> {
+    let parsed = Location::parse(&location)?;
+    check_owner(&app, &parsed, "group-instance-moderate").await?;
+
+    let instance = get_instance(&app, &location).await?;
+    instance
+        .users
+        .ok_or_else(|| format!("The player list of {} is not available", location))
+}
+
+/// Bans a player from the group that owns a group instance. This is a group wide ban, not an
+/// instance one: the player loses access to every instance of the group. Needs a `groupBan`
+/// confirmation token for `{groupId}/{userId}`
+#[tauri::command]
+pub async fn post_vrc_group_ban_from_instance(
+    app: AppHandle,
+    location: String,
+    user_id: String,
+    confirmation: String,
+) -> Result<GroupMember, String> {
+    let location = Location::parse(&location)?;
+    let group_id = location
+        .group_id
+        .clone()
+        .ok_or_else(|| format!("{} is not a group instance", location))?;
+    check_owner(&app, &location, "group-instance-moderate").await?;
+
+    post_vrc_group_ban(app, group_id, user_id, confirmation).await
+}
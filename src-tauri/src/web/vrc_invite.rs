@@ -0,0 +1,97 @@
+use crate::types::location::Location;
+use crate::types::notification::{validate_message_slot, Notification, RequestInvite, SendInvite};
+use crate::types::request::Request;
+use crate::types::user::validate_user_id;
+use crate::web::vrc_request::vrc_typed_request;
+use tauri::AppHandle;
+
+/// Invites a user to an instance, optionally using one of the user's invite message slots
+#[tauri::command]
+pub async fn post_vrc_invite(
+    app: AppHandle,
+    user_id: String,
+    location: String,
+    message_slot: Option<u8>,
+) -> Result<Notification, String> {
+    validate_user_id(&user_id)?;
+    Location::parse(&location)?;
+    if let Some(slot) = message_slot {
+        validate_message_slot(slot)?;
+    }
+
+    let url = format!("https://api.vrchat.cloud/api/1/invite/{}", user_id);
+
+    let body = serde_json::to_value(SendInvite {
+        instance_id: location,
+        message_slot,
+    })
+    .map_err(|e| format!("Failed to serialize invite: {}", e))?;
+
+    let req = Request {
+        url,
+        method: "POST".to_string(),
+        headers: None,
+        body: Some(body),
+    };
+
+    match vrc_typed_request::<Notification>(app, req).await {
+        Ok(response) => Ok(response),
+        Err(e) => Err(format!("Error sending invite!: {}", e)),
+    }
+}
+
+/// Asks a user for an invite to their instance, optionally using one of the request message slots
+#[tauri::command]
+pub async fn post_vrc_request_invite(
+    app: AppHandle,
+    user_id: String,
+    request_slot: Option<u8>,
+) -> Result<Notification, String> {
+    validate_user_id(&user_id)?;
+    if let Some(slot) = request_slot {
+        validate_message_slot(slot)?;
+    }
+
+    let url = format!("https://api.vrchat.cloud/api/1/requestInvite/{}", user_id);
+
+    let body = serde_json::to_value(RequestInvite { request_slot })
+        .map_err(|e| format!("Failed to serialize invite request: {}", e))?;
+
+    let req = Request {
+        url,
+        method: "POST".to_string(),
+        headers: None,
+        body: Some(body),
+    };
+
+    match vrc_typed_request::<Notification>(app, req).await {
+        Ok(response) => Ok(response),
+        Err(e) => Err(format!("Error requesting invite!: {}", e)),
+    }
+}
+
+/// Sends an invite to the current user for an instance, which shows up in game
+#[tauri::command]
+pub async fn post_vrc_invite_myself(
+    app: AppHandle,
+    location: String,
+) -> Result<Notification, String> {
+    Location::parse(&location)?;
+
+    let url = format!(
+        "https://api.vrchat.cloud/api/1/invite/myself/to/{}",
+        location
+    );
+
+    let req = Request {
+        url,
+        method: "POST".to_string(),
+        headers: None,
+        body: None,
+    };
+
+    match vrc_typed_request::<Notification>(app, req).await {
+        Ok(response) => Ok(response),
+        Err(e) => Err(format!("Error inviting yourself!: {}", e)),
+    }
+}
@@ -1,5 +1,11 @@
+use crate::db::notifications::{mark_notification_history_seen, set_notification_action};
+use crate::types::notification::{
+    validate_message_slot, InviteResponse, Notification, NotificationV2, NotificationV2RespondBody,
+    NotificationV2Response,
+};
 use crate::types::request::Request;
-use crate::web::vrc_request::vrc_get_request;
+use crate::types::response::Success;
+use crate::web::vrc_request::{vrc_get_request, vrc_typed_request};
 use tauri::AppHandle;
 
 #[tauri::command]
@@ -37,11 +43,216 @@ pub async fn put_vrc_see_notification(
         body: None,
     };
 
-    match vrc_get_request(app, req).await {
+    let response = vrc_get_request(app.clone(), req)
+        .await
+        .map_err(|e| format!("Error setting notification to read!: {}", e))?;
+
+    if let Err(e) = mark_notification_history_seen(app, notification_id).await {
+        log::warn!("{}", e);
+    }
+
+    Ok(response)
+}
+
+/// Accepts a friend request notification
+#[tauri::command]
+pub async fn put_vrc_accept_notification(
+    app: AppHandle,
+    notification_id: String,
+) -> Result<Success, String> {
+    let url = format!(
+        "https://api.vrchat.cloud/api/1/auth/user/notifications/{}/accept",
+        notification_id
+    );
+
+    let req = Request {
+        url,
+        method: "PUT".to_string(),
+        headers: None,
+        body: None,
+    };
+
+    let response = vrc_typed_request::<Success>(app.clone(), req)
+        .await
+        .map_err(|e| format!("Error accepting notification!: {}", e))?;
+
+    if let Err(e) = set_notification_action(&app, &notification_id, "accepted").await {
+        log::warn!("{}", e);
+    }
+
+    Ok(response)
+}
+
+/// Hides a notification, this is also how VRChat declines friend requests and deletes notifications
+#[tauri::command]
+pub async fn put_vrc_hide_notification(
+    app: AppHandle,
+    notification_id: String,
+) -> Result<Notification, String> {
+    let url = format!(
+        "https://api.vrchat.cloud/api/1/auth/user/notifications/{}/hide",
+        notification_id
+    );
+
+    let req = Request {
+        url,
+        method: "PUT".to_string(),
+        headers: None,
+        body: None,
+    };
+
+    let response = vrc_typed_request::<Notification>(app.clone(), req)
+        .await
+        .map_err(|e| format!("Error hiding notification!: {}", e))?;
+
+    if let Err(e) = set_notification_action(&app, &notification_id, "hidden").await {
+        log::warn!("{}", e);
+    }
+
+    Ok(response)
+}
+
+/// Clears all notifications
+#[tauri::command]
+pub async fn put_vrc_clear_notifications(app: AppHandle) -> Result<Success, String> {
+    let url = "https://api.vrchat.cloud/api/1/auth/user/notifications/clear".to_string();
+
+    let req = Request {
+        url,
+        method: "PUT".to_string(),
+        headers: None,
+        body: None,
+    };
+
+    match vrc_typed_request::<Success>(app, req).await {
         Ok(response) => Ok(response),
-        Err(e) => Err(format!(
-            "Error setting notification to read!: {}",
-            e.to_string()
-        )),
+        Err(e) => Err(format!("Error clearing notifications!: {}", e)),
     }
 }
+
+/// Responds to an invite or invite request notification using one of the user's message slots
+#[tauri::command]
+pub async fn post_vrc_invite_response(
+    app: AppHandle,
+    notification_id: String,
+    response_slot: u8,
+) -> Result<Notification, String> {
+    validate_message_slot(response_slot)?;
+
+    let url = format!(
+        "https://api.vrchat.cloud/api/1/invite/{}/response",
+        notification_id
+    );
+
+    let body = serde_json::to_value(InviteResponse { response_slot })
+        .map_err(|e| format!("Failed to serialize invite response: {}", e))?;
+
+    let req = Request {
+        url,
+        method: "POST".to_string(),
+        headers: None,
+        body: Some(body),
+    };
+
+    let response = vrc_typed_request::<Notification>(app.clone(), req)
+        .await
+        .map_err(|e| format!("Error responding to invite!: {}", e))?;
+
+    if let Err(e) = set_notification_action(&app, &notification_id, "responded").await {
+        log::warn!("{}", e);
+    }
+
+    Ok(response)
+}
+
+/// Gets a page of v2 notifications (group, system and other non legacy notifications)
+#[tauri::command]
+pub async fn get_vrc_notifications_v2(
+    app: AppHandle,
+    offset: u16,
+) -> Result<Vec<NotificationV2>, String> {
+    let url = format!(
+        "https://api.vrchat.cloud/api/1/notifications?n=100&offset={}",
+        offset
+    );
+
+    let req = Request {
+        url,
+        method: "GET".to_string(),
+        headers: None,
+        body: None,
+    };
+
+    match vrc_typed_request::<Vec<NotificationV2>>(app, req).await {
+        Ok(response) => Ok(response),
+        Err(e) => Err(format!("Error getting v2 notifications!: {}", e)),
+    }
+}
+
+/// Responds to a v2 notification with one of the response options it offers
+#[tauri::command]
+pub async fn post_vrc_notification_v2_response(
+    app: AppHandle,
+    notification_id: String,
+    response: NotificationV2Response,
+) -> Result<Success, String> {
+    let url = format!(
+        "https://api.vrchat.cloud/api/1/notifications/{}/respond",
+        notification_id
+    );
+
+    let action = response.response_type.clone();
+
+    let body = serde_json::to_value(NotificationV2RespondBody {
+        notification_id: notification_id.clone(),
+        response_type: response.response_type,
+        response_data: response.data,
+    })
+    .map_err(|e| format!("Failed to serialize notification response: {}", e))?;
+
+    let req = Request {
+        url,
+        method: "POST".to_string(),
+        headers: None,
+        body: Some(body),
+    };
+
+    let response = vrc_typed_request::<Success>(app.clone(), req)
+        .await
+        .map_err(|e| format!("Error responding to notification!: {}", e))?;
+
+    if let Err(e) = set_notification_action(&app, &notification_id, &action).await {
+        log::warn!("{}", e);
+    }
+
+    Ok(response)
+}
+
+/// Acknowledges a v2 notification, marking it as seen
+#[tauri::command]
+pub async fn put_vrc_see_notification_v2(
+    app: AppHandle,
+    notification_id: String,
+) -> Result<NotificationV2, String> {
+    let url = format!(
+        "https://api.vrchat.cloud/api/1/notifications/{}/see",
+        notification_id
+    );
+
+    let req = Request {
+        url,
+        method: "PUT".to_string(),
+        headers: None,
+        body: None,
+    };
+
+    let response = vrc_typed_request::<NotificationV2>(app.clone(), req)
+        .await
+        .map_err(|e| format!("Error acknowledging notification!: {}", e))?;
+
+    if let Err(e) = mark_notification_history_seen(app, notification_id).await {
+        log::warn!("{}", e);
+    }
+
+    Ok(response)
+}
@@ -0,0 +1,127 @@
+use crate::db::player_moderations::{
+    add_player_moderation, remove_player_moderation, replace_player_moderations,
+};
+use crate::types::moderation::{ModeratePlayer, PlayerModeration, PlayerModerationType};
+use crate::types::request::Request;
+use crate::types::response::Success;
+use crate::web::vrc_request::vrc_typed_request;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::Duration;
+use tauri::AppHandle;
+
+const SYNC_INTERVAL: Duration = Duration::from_secs(10 * 60);
+
+static SYNC_STARTED: AtomicBool = AtomicBool::new(false);
+
+/// Gets the current user's player moderations, optionally of a single type
+#[tauri::command]
+pub async fn get_vrc_player_moderations(
+    app: AppHandle,
+    moderation_type: Option<PlayerModerationType>,
+) -> Result<Vec<PlayerModeration>, String> {
+    let mut url = "https://api.vrchat.cloud/api/1/auth/user/playermoderations".to_string();
+    if let Some(moderation_type) = moderation_type {
+        url.push_str(&format!("?type={}", moderation_type));
+    }
+
+    let req = Request {
+        url,
+        method: "GET".to_string(),
+        headers: None,
+        body: None,
+    };
+
+    match vrc_typed_request::<Vec<PlayerModeration>>(app, req).await {
+        Ok(response) => Ok(response),
+        Err(e) => Err(format!("Error getting player moderations!: {}", e)),
+    }
+}
+
+/// Blocks, mutes or hides the avatar of a user
+#[tauri::command]
+pub async fn post_vrc_player_moderation(
+    app: AppHandle,
+    user_id: String,
+    moderation_type: PlayerModerationType,
+) -> Result<PlayerModeration, String> {
+    let body = serde_json::to_value(ModeratePlayer {
+        moderated: user_id,
+        moderation_type,
+    })
+    .map_err(|e| format!("Failed to serialize player moderation: {}", e))?;
+
+    let req = Request {
+        url: "https://api.vrchat.cloud/api/1/auth/user/playermoderations".to_string(),
+        method: "POST".to_string(),
+        headers: None,
+        body: Some(body),
+    };
+
+    let moderation = vrc_typed_request::<PlayerModeration>(app.clone(), req)
+        .await
+        .map_err(|e| format!("Error moderating player!: {}", e))?;
+
+    if let Err(e) = add_player_moderation(&app, &moderation).await {
+        log::warn!("{}", e);
+    }
+
+    Ok(moderation)
+}
+
+/// Removes a moderation of a type from a user
+#[tauri::command]
+pub async fn put_vrc_remove_player_moderation(
+    app: AppHandle,
+    user_id: String,
+    moderation_type: PlayerModerationType,
+) -> Result<Success, String> {
+    let body = serde_json::to_value(ModeratePlayer {
+        moderated: user_id.clone(),
+        moderation_type,
+    })
+    .map_err(|e| format!("Failed to serialize player moderation: {}", e))?;
+
+    let req = Request {
+        url: "https://api.vrchat.cloud/api/1/auth/user/unplayermoderate".to_string(),
+        method: "PUT".to_string(),
+        headers: None,
+        body: Some(body),
+    };
+
+    let response = vrc_typed_request::<Success>(app.clone(), req)
+        .await
+        .map_err(|e| format!("Error removing player moderation!: {}", e))?;
+
+    if let Err(e) = remove_player_moderation(&app, &user_id, &moderation_type.to_string()).await {
+        log::warn!("{}", e);
+    }
+
+    Ok(response)
+}
+
+/// Replaces the local moderation table with the current list from the API
+#[tauri::command]
+pub async fn sync_vrc_player_moderations(app: AppHandle) -> Result<usize, String> {
+    let moderations = get_vrc_player_moderations(app.clone(), None).await?;
+    replace_player_moderations(&app, &moderations).await?;
+
+    Ok(moderations.len())
+}
+
+/// Keeps the local moderation table in sync in the background, started once the user is
+/// logged in and only once per run
+pub fn start_sync(app: AppHandle) {
+    if SYNC_STARTED.swap(true, Ordering::SeqCst) {
+        return;
+    }
+
+    tauri::async_runtime::spawn(async move {
+        loop {
+            // Failures are expected after logging out, the next interval retries
+            if let Err(e) = sync_vrc_player_moderations(app.clone()).await {
+                log::debug!("Player moderation sync skipped: {}", e);
+            }
+            tokio::time::sleep(SYNC_INTERVAL).await;
+        }
+    });
+}
@@ -1,21 +1,36 @@
+use serde::de::DeserializeOwned;
 use std::sync::Arc;
-use tauri::AppHandle;
+use std::time::Duration;
+use tauri::http::header::CONTENT_TYPE;
+use tauri::{AppHandle, Manager};
 use tauri_plugin_http::reqwest::cookie::Jar;
 use tauri_plugin_http::reqwest::header::USER_AGENT;
-use tauri_plugin_http::reqwest::Client;
+use tauri_plugin_http::reqwest::{Client, Method};
 
-use crate::types::request::Request;
+use crate::types::request::{Request, RequestError};
+use crate::web::cache::ResponseCache;
 use crate::web::cookies;
+use crate::web::rate_limit::RateLimiter;
 
-/// Makes a get request using VRChat authentication cookies
+/// Makes a request using VRChat authentication cookies, honoring the request method, headers and body.
+/// Every request goes through the shared rate limiter
 #[tauri::command]
 pub async fn vrc_get_request(app: AppHandle, req: Request) -> Result<String, String> {
+    Ok(vrc_request(app, req).await?)
+}
+
+/// Same as `vrc_get_request`, but keeps the status of failed responses for callers that handle
+/// some of them, like a 404 for something that was removed
+pub async fn vrc_request(app: AppHandle, req: Request) -> Result<String, RequestError> {
     let url = &req.url;
 
     if !url.starts_with("http://") && !url.starts_with("https://") {
-        Err("URL must start with https://")?;
+        return Err("URL must start with https://".to_string().into());
     }
 
+    let method = Method::from_bytes(req.method.to_uppercase().as_bytes())
+        .map_err(|e| format!("Invalid request method {}: {}", req.method, e))?;
+
     let cookie_store = Arc::new(Jar::default());
 
     if let Ok(Some(cookies)) = cookies::load_login_cookies(app.clone()) {
@@ -27,19 +42,80 @@ pub async fn vrc_get_request(app: AppHandle, req: Request) -> Result<String, Str
         .build()
         .map_err(|e| format!("Failed to build client: {}", e))?;
 
-    let request = client.get(url.clone()).header(USER_AGENT, "Spectre/2.0");
+    let mut request = client
+        .request(method, url.clone())
+        .header(USER_AGENT, "Spectre/2.0");
+
+    if let Some(headers) = &req.headers {
+        for (key, value) in headers {
+            request = request.header(key.as_str(), value.as_str());
+        }
+    }
+
+    if let Some(body) = &req.body {
+        request = request
+            .header(CONTENT_TYPE, "application/json")
+            .body(body.to_string());
+    }
+
+    app.state::<RateLimiter>().acquire().await;
 
     match request.send().await {
         Ok(res) => {
             if res.status().is_success() {
                 match res.text().await {
                     Ok(text) => Ok(text),
-                    Err(e) => Err(format!("Failed to read response text: {}", e)),
+                    Err(e) => Err(format!("Failed to read response text: {}", e).into()),
                 }
             } else {
-                Err(format!("Request failed with status: {}", res.status()))
+                Err(RequestError {
+                    status: Some(res.status().as_u16()),
+                    message: format!("Request failed with status: {}", res.status()),
+                })
             }
         }
-        Err(e) => Err(format!("Request failed: {}", e)),
+        Err(e) => Err(format!("Request failed: {}", e).into()),
+    }
+}
+
+/// Makes a request with `vrc_get_request` and deserializes the response body into `T`
+pub async fn vrc_typed_request<T: DeserializeOwned>(
+    app: AppHandle,
+    req: Request,
+) -> Result<T, String> {
+    let text = vrc_get_request(app, req).await?;
+
+    serde_json::from_str::<T>(&text).map_err(|e| format!("Failed to parse response: {}", e))
+}
+
+/// Makes a GET request, reusing a cached response body if one is younger than `ttl`
+pub async fn vrc_cached_request(
+    app: AppHandle,
+    req: Request,
+    ttl: Duration,
+) -> Result<String, String> {
+    if !req.method.eq_ignore_ascii_case("GET") {
+        return Err("Only GET requests can be cached".to_string());
+    }
+
+    if let Some(body) = app.state::<ResponseCache>().get(&req.url, ttl) {
+        return Ok(body);
     }
+
+    let url = req.url.clone();
+    let body = vrc_get_request(app.clone(), req).await?;
+    app.state::<ResponseCache>().insert(url, body.clone());
+
+    Ok(body)
+}
+
+/// Makes a cached request with `vrc_cached_request` and deserializes the response body into `T`
+pub async fn vrc_cached_typed_request<T: DeserializeOwned>(
+    app: AppHandle,
+    req: Request,
+    ttl: Duration,
+) -> Result<T, String> {
+    let text = vrc_cached_request(app, req, ttl).await?;
+
+    serde_json::from_str::<T>(&text).map_err(|e| format!("Failed to parse response: {}", e))
 }
@@ -1,5 +1,7 @@
+use crate::db::user_notes::cache_user_note;
 use crate::types::request::Request;
-use crate::web::vrc_request::vrc_get_request;
+use crate::types::user::{CurrentUser, UpdateUser};
+use crate::web::vrc_request::{vrc_get_request, vrc_typed_request};
 use tauri::AppHandle;
 
 #[tauri::command]
@@ -13,8 +15,60 @@ pub async fn get_vrc_user(app: AppHandle, user_id: String) -> Result<String, Str
         body: None,
     };
 
-    match vrc_get_request(app, req).await {
+    let response = vrc_get_request(app.clone(), req)
+        .await
+        .map_err(|e| format!("Error getting user!: {}", e))?;
+
+    // Users include the current user's note about them, keep the note cache up to date with it
+    if let Ok(user) = serde_json::from_str::<serde_json::Value>(&response) {
+        if let Some(note) = user.get("note").and_then(|note| note.as_str()) {
+            if let Err(e) = cache_user_note(&app, &user_id, note).await {
+                log::warn!("{}", e);
+            }
+        }
+    }
+
+    Ok(response)
+}
+
+/// Gets the logged in user, this is the same `auth/user` call login uses
+pub async fn get_current_user(app: &AppHandle) -> Result<CurrentUser, String> {
+    let req = Request {
+        url: "https://api.vrchat.cloud/api/1/auth/user".to_string(),
+        method: "GET".to_string(),
+        headers: None,
+        body: None,
+    };
+
+    match vrc_typed_request::<CurrentUser>(app.clone(), req).await {
+        Ok(response) => Ok(response),
+        Err(e) => Err(format!("Error getting current user!: {}", e)),
+    }
+}
+
+/// Updates the current user's status, status description, bio, bio links and pronouns
+#[tauri::command]
+pub async fn put_vrc_update_user(
+    app: AppHandle,
+    user_id: String,
+    update: UpdateUser,
+) -> Result<CurrentUser, String> {
+    update.validate()?;
+
+    let url = format!("https://api.vrchat.cloud/api/1/users/{}", user_id);
+
+    let body = serde_json::to_value(&update)
+        .map_err(|e| format!("Failed to serialize user update: {}", e))?;
+
+    let req = Request {
+        url,
+        method: "PUT".to_string(),
+        headers: None,
+        body: Some(body),
+    };
+
+    match vrc_typed_request::<CurrentUser>(app, req).await {
         Ok(response) => Ok(response),
-        Err(e) => Err(format!("Error getting user!: {}", e.to_string())),
+        Err(e) => Err(format!("Error updating user!: {}", e)),
     }
 }
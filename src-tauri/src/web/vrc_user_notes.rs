@@ -0,0 +1,84 @@
+use crate::db::user_notes::{cache_user_note, get_cached_user_note};
+use crate::types::request::Request;
+use crate::types::user::{UpdateUserNote, UserNote};
+use crate::web::vrc_request::vrc_typed_request;
+use crate::web::vrc_user::get_vrc_user;
+use tauri::AppHandle;
+
+/// Gets a page of the current user's notes and refreshes the local note cache with them
+#[tauri::command]
+pub async fn get_vrc_user_notes(app: AppHandle, offset: u16) -> Result<Vec<UserNote>, String> {
+    let url = format!(
+        "https://api.vrchat.cloud/api/1/userNotes?n=100&offset={}",
+        offset
+    );
+
+    let req = Request {
+        url,
+        method: "GET".to_string(),
+        headers: None,
+        body: None,
+    };
+
+    let notes = vrc_typed_request::<Vec<UserNote>>(app.clone(), req)
+        .await
+        .map_err(|e| format!("Error getting user notes!: {}", e))?;
+
+    for note in &notes {
+        if let Err(e) = cache_user_note(&app, &note.target_user_id, &note.note).await {
+            log::warn!("{}", e);
+        }
+    }
+
+    Ok(notes)
+}
+
+/// Gets the note for a user, from the local cache if possible
+#[tauri::command]
+pub async fn get_vrc_user_note(app: AppHandle, user_id: String) -> Result<String, String> {
+    if let Some(cached) = get_cached_user_note(&app, &user_id).await? {
+        return Ok(cached.note);
+    }
+
+    // get_vrc_user caches the note included with the user
+    let user = get_vrc_user(app, user_id).await?;
+    let user = serde_json::from_str::<serde_json::Value>(&user)
+        .map_err(|e| format!("Failed to parse user: {}", e))?;
+
+    Ok(user
+        .get("note")
+        .and_then(|note| note.as_str())
+        .unwrap_or_default()
+        .to_string())
+}
+
+/// Sets the note for a user, an empty note removes it
+#[tauri::command]
+pub async fn post_vrc_user_note(
+    app: AppHandle,
+    user_id: String,
+    note: String,
+) -> Result<UserNote, String> {
+    let body = serde_json::to_value(UpdateUserNote {
+        target_user_id: user_id.clone(),
+        note: note.clone(),
+    })
+    .map_err(|e| format!("Failed to serialize user note: {}", e))?;
+
+    let req = Request {
+        url: "https://api.vrchat.cloud/api/1/userNotes".to_string(),
+        method: "POST".to_string(),
+        headers: None,
+        body: Some(body),
+    };
+
+    let response = vrc_typed_request::<UserNote>(app.clone(), req)
+        .await
+        .map_err(|e| format!("Error updating user note!: {}", e))?;
+
+    if let Err(e) = cache_user_note(&app, &user_id, &note).await {
+        log::warn!("{}", e);
+    }
+
+    Ok(response)
+}
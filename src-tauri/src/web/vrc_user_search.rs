@@ -0,0 +1,169 @@
+use crate::db::logs::{get_log_names_missing_user, set_log_user_by_name};
+use crate::settings::{load_setting, save_setting};
+use crate::types::request::Request;
+use crate::types::user::LimitedUser;
+use crate::web::vrc_request::{vrc_cached_typed_request, vrc_typed_request};
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::time::Duration;
+use tauri::AppHandle;
+use tauri_plugin_http::reqwest::Url;
+
+const FRIENDS_TTL: Duration = Duration::from_secs(5 * 60);
+const PAGE_SIZE: usize = 100;
+
+// Display names no user was found for and when, they are only searched again after a while in
+// case the user has changed their name back or the account was hidden from search
+const UNRESOLVED_KEY: &str = "unresolvedLogNames";
+const UNRESOLVED_RETRY_SECS: i64 = 7 * 24 * 60 * 60;
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub enum ResolvedFrom {
+    Friends,
+    Search,
+}
+
+/// A display name resolved to a user id
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ResolvedUser {
+    pub id: String,
+    pub display_name: String,
+    pub source: ResolvedFrom,
+}
+
+/// Searches users by display name
+#[tauri::command]
+pub async fn search_vrc_users(
+    app: AppHandle,
+    search: String,
+    offset: u16,
+) -> Result<Vec<LimitedUser>, String> {
+    let url = Url::parse_with_params(
+        "https://api.vrchat.cloud/api/1/users",
+        &[
+            ("search", search),
+            ("n", PAGE_SIZE.to_string()),
+            ("offset", offset.to_string()),
+        ],
+    )
+    .map_err(|e| format!("Failed to build search url: {}", e))?;
+
+    let req = Request {
+        url: url.to_string(),
+        method: "GET".to_string(),
+        headers: None,
+        body: None,
+    };
+
+    match vrc_typed_request::<Vec<LimitedUser>>(app, req).await {
+        Ok(response) => Ok(response),
+        Err(e) => Err(format!("Error searching users!: {}", e)),
+    }
+}
+
+/// Gets every friend, online and offline, through the shared cache
+async fn get_cached_friends(app: &AppHandle) -> Result<Vec<LimitedUser>, String> {
+    let mut friends = Vec::new();
+
+    for offline in [false, true] {
+        let mut offset = 0;
+        loop {
+            let req = Request {
+                url: format!(
+                    "https://api.vrchat.cloud/api/1/auth/user/friends?n={}&offset={}&offline={}",
+                    PAGE_SIZE, offset, offline
+                ),
+                method: "GET".to_string(),
+                headers: None,
+                body: None,
+            };
+
+            let page =
+                vrc_cached_typed_request::<Vec<LimitedUser>>(app.clone(), req, FRIENDS_TTL).await?;
+            let len = page.len();
+            friends.extend(page);
+
+            if len < PAGE_SIZE {
+                break;
+            }
+            offset += PAGE_SIZE;
+        }
+    }
+
+    Ok(friends)
+}
+
+/// Resolves a display name to a user, matching friends first and then searching the API.
+/// Display names are unique on VRChat, so only exact (case insensitive) matches are returned
+pub async fn resolve_display_name(
+    app: &AppHandle,
+    display_name: &str,
+) -> Result<Option<ResolvedUser>, String> {
+    let matches = |user: &LimitedUser| user.display_name.eq_ignore_ascii_case(display_name.trim());
+
+    match get_cached_friends(app).await {
+        Ok(friends) => {
+            if let Some(friend) = friends.into_iter().find(|friend| matches(friend)) {
+                return Ok(Some(ResolvedUser {
+                    id: friend.id,
+                    display_name: friend.display_name,
+                    source: ResolvedFrom::Friends,
+                }));
+            }
+        }
+        Err(e) => log::warn!("Failed to get friends while resolving a name: {}", e),
+    }
+
+    let users = search_vrc_users(app.clone(), display_name.trim().to_string(), 0).await?;
+    Ok(users
+        .into_iter()
+        .find(|user| matches(user))
+        .map(|user| ResolvedUser {
+            id: user.id,
+            display_name: user.display_name,
+            source: ResolvedFrom::Search,
+        }))
+}
+
+#[tauri::command]
+pub async fn resolve_vrc_user_by_name(
+    app: AppHandle,
+    display_name: String,
+) -> Result<Option<ResolvedUser>, String> {
+    resolve_display_name(&app, &display_name).await
+}
+
+/// Fills in the user id of game log join and leave rows that were logged without one,
+/// each display name is resolved once for all of its rows
+#[tauri::command]
+pub async fn resolve_log_user_ids(app: AppHandle) -> Result<u64, String> {
+    let names = get_log_names_missing_user(&app).await?;
+
+    let now = Utc::now().timestamp();
+    let mut unresolved: HashMap<String, i64> =
+        load_setting(&app, UNRESOLVED_KEY)?.unwrap_or_default();
+    unresolved.retain(|_, missed| now - *missed < UNRESOLVED_RETRY_SECS);
+
+    let mut resolved = 0;
+    for display_name in names {
+        if unresolved.contains_key(&display_name) {
+            continue;
+        }
+
+        match resolve_display_name(&app, &display_name).await {
+            Ok(Some(user)) => {
+                resolved += set_log_user_by_name(&app, &display_name, &user.id).await?
+            }
+            Ok(None) => {
+                unresolved.insert(display_name, now);
+            }
+            Err(e) => log::warn!("Failed to resolve {}: {}", display_name, e),
+        }
+    }
+
+    save_setting(&app, UNRESOLVED_KEY, &unresolved)?;
+    Ok(resolved)
+}
@@ -1,6 +1,12 @@
 use crate::types::request::Request;
-use crate::web::vrc_request::vrc_get_request;
+use crate::types::world::{World, WorldSearch};
+use crate::web::vrc_request::{vrc_cached_request, vrc_cached_typed_request};
+use std::time::Duration;
 use tauri::AppHandle;
+use tauri_plugin_http::reqwest::Url;
+
+const WORLD_TTL: Duration = Duration::from_secs(10 * 60);
+const SEARCH_TTL: Duration = Duration::from_secs(60);
 
 #[tauri::command]
 pub async fn get_vrc_world(app: AppHandle, world_id: String) -> Result<String, String> {
@@ -13,8 +19,75 @@ pub async fn get_vrc_world(app: AppHandle, world_id: String) -> Result<String, S
         body: None,
     };
 
-    match vrc_get_request(app, req).await {
+    match vrc_cached_request(app, req, WORLD_TTL).await {
+        Ok(response) => Ok(response),
+        Err(e) => Err(format!("Error getting world!: {}", e)),
+    }
+}
+
+/// Runs a world search against one of the `worlds` listing endpoints
+async fn search_worlds(
+    app: AppHandle,
+    endpoint: &str,
+    search: &WorldSearch,
+) -> Result<Vec<World>, String> {
+    let url = Url::parse_with_params(
+        &format!("https://api.vrchat.cloud/api/1/{}", endpoint),
+        search.query(),
+    )
+    .map_err(|e| format!("Failed to build search url: {}", e))?;
+
+    let req = Request {
+        url: url.to_string(),
+        method: "GET".to_string(),
+        headers: None,
+        body: None,
+    };
+
+    vrc_cached_typed_request::<Vec<World>>(app, req, SEARCH_TTL).await
+}
+
+/// Searches worlds by text, tags and featured state, with sorting and paging
+#[tauri::command]
+pub async fn search_vrc_worlds(app: AppHandle, search: WorldSearch) -> Result<Vec<World>, String> {
+    match search_worlds(app, "worlds", &search).await {
+        Ok(response) => Ok(response),
+        Err(e) => Err(format!("Error searching worlds!: {}", e)),
+    }
+}
+
+/// Gets worlds that currently have players in them
+#[tauri::command]
+pub async fn get_vrc_active_worlds(
+    app: AppHandle,
+    search: WorldSearch,
+) -> Result<Vec<World>, String> {
+    match search_worlds(app, "worlds/active", &search).await {
+        Ok(response) => Ok(response),
+        Err(e) => Err(format!("Error getting active worlds!: {}", e)),
+    }
+}
+
+/// Gets worlds the current user recently visited
+#[tauri::command]
+pub async fn get_vrc_recent_worlds(
+    app: AppHandle,
+    search: WorldSearch,
+) -> Result<Vec<World>, String> {
+    match search_worlds(app, "worlds/recent", &search).await {
+        Ok(response) => Ok(response),
+        Err(e) => Err(format!("Error getting recent worlds!: {}", e)),
+    }
+}
+
+/// Gets worlds the current user has favorited
+#[tauri::command]
+pub async fn get_vrc_favorite_worlds(
+    app: AppHandle,
+    search: WorldSearch,
+) -> Result<Vec<World>, String> {
+    match search_worlds(app, "worlds/favorites", &search).await {
         Ok(response) => Ok(response),
-        Err(e) => Err(format!("Error getting world!: {}", e.to_string())),
+        Err(e) => Err(format!("Error getting favorite worlds!: {}", e)),
     }
 }
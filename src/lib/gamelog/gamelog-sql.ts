@@ -106,7 +106,7 @@ export async function addLog(log: string) {
 				time: new Date(),
 				type: 'OnPlayerJoined',
 				message: info.username,
-				user: info.userId
+				user: info.userId ?? undefined
 			};
 
 			gamelogStore.update((logs) => {
@@ -128,7 +128,7 @@ export async function addLog(log: string) {
 				time: new Date(),
 				type: "OnPlayerLeft",
 				message: info.username,
-				user: info.userId
+				user: info.userId ?? undefined
 			};
 
 			gamelogStore.update(logs => {
@@ -138,22 +138,22 @@ export async function addLog(log: string) {
 	}
 }
 
-/** Simple function to parse join logs for relevant info **/
+/** Simple function to parse join logs for relevant info, older logs only have the display name **/
 function parseJoinLog(line: string): JoinInfo | null {
-	const re = /OnPlayerJoined\s+(.+?)\s*\((usr_[^)\s]+)\)/;
+	const re = /OnPlayerJoined\s+(.+?)(?:\s*\((usr_[^)\s]+)\))?\s*$/;
 	const m = re.exec(line);
 	if (!m) return null;
 	const [, username, userId] = m;
-	return { username, userId };
+	return { username, userId: userId ?? null };
 }
 
-/** Simple function to parse leave logs for relevant info **/
+/** Simple function to parse leave logs for relevant info, older logs only have the display name **/
 function parseLeaveLog(line: string): JoinInfo | null {
-	const re = /OnPlayerLeft\s+(.+?)\s*\((usr_[^)\s]+)\)/;
+	const re = /OnPlayerLeft\s+(.+?)(?:\s*\((usr_[^)\s]+)\))?\s*$/;
 	const m = re.exec(line);
 	if (!m) return null;
 	const [, username, userId] = m;
-	return { username, userId };
+	return { username, userId: userId ?? null };
 }
 
 export interface GameLogMessage {
@@ -166,5 +166,6 @@ export interface GameLogMessage {
 
 interface JoinInfo {
 	username: string;
-	userId: string;
+	/** Missing on name only lines, `resolve_log_user_ids` fills these in later **/
+	userId: string | null;
 }
\ No newline at end of file
@@ -0,0 +1,7 @@
+export interface CapacityWatch {
+	location: string;
+	worldId: string;
+	worldName: string | null;
+	createdAt: string;
+	expiresAt: string;
+}
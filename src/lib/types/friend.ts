@@ -1,3 +1,5 @@
+import type { Joinability } from '$lib/types/joinability';
+
 export interface Friend {
 	bio: string;
 	bioLinks: string[];
@@ -19,4 +21,6 @@ export interface Friend {
 	location: string;
 	friendKey: string;
 	platform: string;
+	/** Not part of the friends list response, filled in from `friends-joinability` and location events **/
+	joinability?: Joinability;
 }
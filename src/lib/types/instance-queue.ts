@@ -0,0 +1,22 @@
+export type QueueStatus = 'waiting' | 'ready' | 'left';
+
+export interface QueueState {
+	location: string;
+	status: QueueStatus;
+	position: number | null;
+	queueSize: number | null;
+	expiry: string | null;
+	joinedAt: string;
+	updatedAt: string;
+}
+
+export interface QueueAlert {
+	title: string;
+	message: string;
+}
+
+export interface QueueNotificationSettings {
+	onReady: boolean;
+	onPositionChange: boolean;
+	positionThreshold: number | null;
+}
@@ -0,0 +1,33 @@
+export type JoinReason =
+	| 'offline'
+	| 'private'
+	| 'traveling'
+	| 'invalidLocation'
+	| 'closed'
+	| 'public'
+	| 'friendsPlus'
+	| 'ownInstance'
+	| 'ownerIsFriend'
+	| 'ownerNotFriend'
+	| 'inviteOnly'
+	| 'canRequestInvite'
+	| 'groupPublic'
+	| 'groupPlus'
+	| 'groupMember'
+	| 'notGroupMember'
+	| 'roleRestricted'
+	| 'ageGated'
+	| 'full'
+	| 'queue';
+
+export interface Joinability {
+	joinable: boolean;
+	reason: JoinReason;
+	canRequestInvite: boolean;
+}
+
+export interface FriendJoinability {
+	userId: string;
+	location: string;
+	joinability: Joinability;
+}
@@ -0,0 +1,33 @@
+export type InstanceAccess =
+	| 'public'
+	| 'friends+'
+	| 'friends'
+	| 'invite+'
+	| 'invite'
+	| 'group'
+	| 'group-public'
+	| 'group+';
+
+export type GroupAccessType = 'members' | 'plus' | 'public';
+
+export interface Location {
+	worldId: string;
+	instanceName: string;
+	access: InstanceAccess;
+	ownerId: string | null;
+	// Raw region so new regions don't break parsing
+	region: string | null;
+	groupId: string | null;
+	groupAccessType: GroupAccessType | null;
+	nonce: string | null;
+	strict: boolean;
+	canRequestInvite: boolean;
+	ageGate: boolean;
+	unknown: string[];
+}
+
+export type UserLocation =
+	| { kind: 'offline' }
+	| { kind: 'private' }
+	| { kind: 'traveling' }
+	| ({ kind: 'instance' } & Location);
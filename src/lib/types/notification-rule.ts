@@ -0,0 +1,34 @@
+export type RuleAction = 'notify' | 'desktop' | 'overlay' | 'logOnly' | 'suppress';
+
+export interface TimeWindow {
+	start: string;
+	end: string;
+}
+
+export interface NotificationRule {
+	id: string;
+	name: string;
+	enabled: boolean;
+	eventTypes: string[];
+	friendIds: string[];
+	favoriteGroups: string[];
+	worldIds: string[];
+	quietHours: TimeWindow | null;
+	action: RuleAction;
+}
+
+export interface RuleEvent {
+	eventType: string;
+	userId?: string | null;
+	worldId?: string | null;
+	favoriteGroups?: string[];
+	time?: string;
+}
+
+export interface RuleDecision {
+	ruleId: string | null;
+	action: RuleAction;
+	desktop: boolean;
+	overlay: boolean;
+	log: boolean;
+}
@@ -13,3 +13,42 @@ export interface InviteNotification {
 	worldId: string;
 	worldName: string;
 }
+
+export interface NotificationV2Response {
+	type: string;
+	data: string;
+	icon: string | null;
+	text: string | null;
+}
+
+export interface NotificationV2 {
+	id: string;
+	version: number;
+	type: string;
+	category: string | null;
+	isSystem: boolean;
+	senderUserId: string | null;
+	senderUsername: string | null;
+	receiverUserId: string | null;
+	title: string;
+	message: string;
+	imageUrl: string | null;
+	link: string | null;
+	linkText: string | null;
+	responses: NotificationV2Response[];
+	seen: boolean;
+	canDelete: boolean;
+	createdAt: string | null;
+	data: unknown;
+}
+
+export interface NotificationHistoryEntry {
+	id: string;
+	type: string;
+	sender: string | null;
+	details: string | null;
+	receivedAt: string;
+	seenAt: string | null;
+	respondedAt: string | null;
+	action: string | null;
+}
@@ -0,0 +1,4 @@
+export interface OverlayAlert {
+	title: string;
+	message: string;
+}
@@ -0,0 +1,33 @@
+export type WatchTrigger =
+	| 'comesOnline'
+	| 'goesOffline'
+	| 'joinsJoinableInstance'
+	| 'joinsWorld'
+	| 'statusJoinMe';
+
+export interface WatchlistEntry {
+	id: string;
+	userId: string;
+	displayName: string;
+	triggers: WatchTrigger[];
+	worldIds: string[];
+	cooldownMinutes: number;
+	enabled: boolean;
+}
+
+export interface FriendEvent {
+	eventType: string;
+	userId: string;
+	displayName?: string | null;
+	location?: string | null;
+	worldId?: string | null;
+	worldName?: string | null;
+	status?: string | null;
+	favoriteGroups?: string[];
+}
+
+export interface WatchlistAlert {
+	entryId: string;
+	trigger: WatchTrigger;
+	title: string;
+}
@@ -0,0 +1,6 @@
+import type { WebsocketUser } from '$lib/types/websocket/websocket-user';
+
+export interface WebsocketFriendAdd {
+	userId: string,
+	user: WebsocketUser
+}
\ No newline at end of file
@@ -0,0 +1,3 @@
+export interface WebsocketFriendDelete {
+	userId: string
+}
\ No newline at end of file
@@ -8,6 +8,7 @@ import { invoke } from '@tauri-apps/api/core';
 import type { ExternalUserData } from '$lib/types/external-user';
 import type { WebsocketFriendActive } from '$lib/types/websocket/websocket-friend-active';
 import type { InstanceData } from '$lib/types/instance';
+import type { FriendJoinability, Joinability } from '$lib/types/joinability';
 
 export async function updateFriendLocation(msg: WebsocketFriendLocation) {
 	let needsNewFriend = false;
@@ -32,6 +33,8 @@ export async function updateFriendLocation(msg: WebsocketFriendLocation) {
 		return;
 	}
 
+	await updateFriendJoinability(msg.userId, msg.location);
+
 	if (msg.location != 'private') {
 		let updatedInstanceString = await invoke<string>('get_vrc_instance', {
 			instanceId: msg.location
@@ -61,6 +64,7 @@ export async function updateFriendOffline(msg: WebsocketFriendOffline) {
 
 		current.location = 'offline';
 		current.platform = 'offline';
+		current.joinability = undefined;
 
 		const newMap = new Map(map);
 		newMap.set(msg.userId, current);
@@ -103,7 +107,9 @@ async function insertNewFriend(userId: string) {
 		status: user.status,
 		statusDescription: user.statusDescription,
 		tags: user.tags,
-		userIcon: user.userIcon
+		userIcon: user.userIcon,
+		// Left for the heuristic until the next full load or location update
+		joinability: undefined
 	};
 
 	friendsStore.update((map) => {
@@ -115,6 +121,40 @@ async function insertNewFriend(userId: string) {
 	});
 }
 
+/** Applies the joinability the backend decided after loading the friends list, skipping friends that moved since **/
+export function applyFriendJoinability(friends: FriendJoinability[]) {
+	friendsStore.update((map) => {
+		const newMap = new Map(map);
+		for (const friend of friends) {
+			const current = newMap.get(friend.userId);
+			if (!current || current.location !== friend.location) continue;
+
+			newMap.set(friend.userId, { ...current, joinability: friend.joinability });
+		}
+		return newMap;
+	});
+}
+
+/** Asks the backend whether the friend's new location can be joined **/
+async function updateFriendJoinability(userId: string, location: string) {
+	let joinability: Joinability | undefined;
+	try {
+		joinability = await invoke<Joinability>('get_vrc_joinability', { location: location });
+	} catch (e) {
+		console.error(`Failed to get joinability for ${userId}: ${e}`);
+		joinability = undefined;
+	}
+
+	friendsStore.update((map) => {
+		const current = map.get(userId);
+		if (!current) return map;
+
+		const newMap = new Map(map);
+		newMap.set(userId, { ...current, joinability: joinability });
+		return newMap;
+	});
+}
+
 // Utilities
 function patchFriend(msg: WebsocketFriendLocation): Partial<Friend> {
 	const u = msg.user;
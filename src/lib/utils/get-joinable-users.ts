@@ -7,7 +7,11 @@ export const getJoinableUsers = async () => {
 	let joinableUsersCount = 0;
 
 	friendsData.forEach((friend) => {
-		if (friend.platform != 'web' && friend.location != 'private' && friend.location != 'offline') {
+		if (friend.platform == 'web') return;
+		// The backend fills in joinability shortly after loading friends, until then the location decides
+		if (friend.joinability !== undefined) {
+			if (friend.joinability.joinable) joinableUsersCount++;
+		} else if (friend.location != 'private' && friend.location != 'offline') {
 			joinableUsersCount++;
 		}
 	});
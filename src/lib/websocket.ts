@@ -2,7 +2,11 @@ import { loginStatusStore } from '$lib/svelte-stores';
 import { invoke } from '@tauri-apps/api/core';
 import WebSocket from '@tauri-apps/plugin-websocket';
 import type { WebsocketMessage } from '$lib/types/websocket/websocket-msg';
-import { type InviteNotification, type Notification } from '$lib/types/notification';
+import {
+	type InviteNotification,
+	type Notification,
+	type NotificationV2
+} from '$lib/types/notification';
 import {
 	isPermissionGranted,
 	requestPermission,
@@ -11,7 +15,7 @@ import {
 import type { ExternalUserData } from '$lib/types/external-user';
 import { getSetting } from '$lib/store';
 import { sendXsNotification } from '$lib/xsoverlay/xsocket';
-import { currentInstanceStore } from '$lib/svelte-stores';
+import { currentInstanceStore, favoriteStore } from '$lib/svelte-stores';
 import { get } from 'svelte/store';
 import { addManualLog } from '$lib/gamelog/gamelog-sql';
 import type { InstanceData } from '$lib/types/instance';
@@ -25,7 +29,12 @@ import type { WebsocketFriendLocation } from '$lib/types/websocket/websocket-fri
 import type { WebsocketFriendOffline } from '$lib/types/websocket/websocket-friend-offline';
 import type { WebsocketFriendOnline } from '$lib/types/websocket/websocket-friend-online';
 import type { WebsocketFriendActive } from '$lib/types/websocket/websocket-friend-active';
+import type { WebsocketFriendAdd } from '$lib/types/websocket/websocket-friend-add';
+import type { WebsocketFriendDelete } from '$lib/types/websocket/websocket-friend-delete';
 import { loadData } from '$lib/load-data';
+import type { RuleDecision, RuleEvent } from '$lib/types/notification-rule';
+import type { QueueAlert } from '$lib/types/instance-queue';
+import type { FriendEvent } from '$lib/types/watchlist';
 
 let ws: WebSocket | null = null;
 let permissionGranted: boolean = false;
@@ -146,7 +155,7 @@ export async function checkNotificationPermission() {
 	return await isPermissionGranted();
 }
 
-async function checkXsoEnabled() {
+export async function checkXsoEnabled() {
 	let xsEnabledSetting = await getSetting('xsOverlayEnabled');
 	if (xsEnabledSetting != null) {
 		xsEnabled = xsEnabledSetting.toLowerCase() === 'true';
@@ -174,10 +183,36 @@ async function sendNotif(title: string, msg: string) {
 		await requestNotificationPermission();
 		sendNotification({ title: title, body: msg });
 	}
+}
+
+/** Asks the backend rules engine how an event should be delivered, then delivers it **/
+async function notifyWithRules(
+	event: RuleEvent,
+	title: string,
+	msg: string,
+	logType?: string
+) {
+	if (event.userId) {
+		event.favoriteGroups = get(favoriteStore).get(event.userId)?.tags ?? [];
+	}
+
+	let decision: RuleDecision;
+	try {
+		decision = await invoke<RuleDecision>('evaluate_notification_rules', { event: event });
+	} catch (e) {
+		console.error(`Failed to evaluate notification rules: ${e}`);
+		decision = { ruleId: null, action: 'notify', desktop: true, overlay: true, log: true };
+	}
 
-	if (xsEnabled) {
+	if (decision.desktop) {
+		await sendNotif(title, msg);
+	}
+	if (decision.overlay && xsEnabled) {
 		await sendXsNotification(title);
 	}
+	if (decision.log && logType !== undefined) {
+		await addManualLog(logType, title, event.userId ?? undefined, event.worldId ?? undefined);
+	}
 }
 
 async function getUsernameById(id: string) {
@@ -186,6 +221,36 @@ async function getUsernameById(id: string) {
 	return userObject.displayName;
 }
 
+/** Lets the backend check a friend event against the watchlist, it sends any alerts itself **/
+async function checkWatchlist(event: FriendEvent) {
+	event.favoriteGroups = get(favoriteStore).get(event.userId)?.tags ?? [];
+	try {
+		await invoke('evaluate_friend_watchlist', { event: event });
+	} catch (e) {
+		console.error(`Failed to evaluate friend watchlist: ${e}`);
+	}
+}
+
+/** Stores a received notification in the notification history table **/
+async function recordNotification(
+	id: string | null,
+	type: string,
+	sender: string | null,
+	details: unknown
+) {
+	if (id === null) return;
+	try {
+		await invoke('add_notification_history', {
+			id: id,
+			notificationType: type,
+			sender: sender,
+			details: details ?? null
+		});
+	} catch (e) {
+		console.error(`Failed to record notification ${id}: ${e}`);
+	}
+}
+
 async function handleWebSocketMessage(msgObject: WebsocketMessage) {
 	if (msgObject.type === 'notification') {
 		await checkNotificationPermission();
@@ -193,6 +258,7 @@ async function handleWebSocketMessage(msgObject: WebsocketMessage) {
 		console.log('WebSocket received a notification!');
 
 		let msg: Notification = JSON.parse(msgObject.content);
+		await recordNotification(msg.id, msg.type, msg.senderUserId, msg.details);
 
 		switch (msg.type) {
 			case 'invite': {
@@ -203,9 +269,12 @@ async function handleWebSocketMessage(msgObject: WebsocketMessage) {
 
 					let title = `${username} send you an invite to ${detailsObject.worldName}`;
 
-					await sendNotif(title, msg.message);
-
-					await addManualLog('Invite', title, msg.senderUserId);
+					await notifyWithRules(
+						{ eventType: msg.type, userId: msg.senderUserId, worldId: detailsObject.worldId },
+						title,
+						msg.message,
+						'Invite'
+					);
 				}
 				break;
 			}
@@ -215,9 +284,12 @@ async function handleWebSocketMessage(msgObject: WebsocketMessage) {
 
 					let title = `${username} is requesting an invite!`;
 
-					await sendNotif(title, msg.message);
-
-					await addManualLog('Invite Request', title, msg.senderUserId);
+					await notifyWithRules(
+						{ eventType: msg.type, userId: msg.senderUserId },
+						title,
+						msg.message,
+						'Invite Request'
+					);
 				}
 				break;
 			}
@@ -227,9 +299,12 @@ async function handleWebSocketMessage(msgObject: WebsocketMessage) {
 
 					let title = `${username} sent you a friend request!`;
 
-					await sendNotif(title, msg.message);
-
-					await addManualLog('Friend Request', title, msg.senderUserId);
+					await notifyWithRules(
+						{ eventType: msg.type, userId: msg.senderUserId },
+						title,
+						msg.message,
+						'Friend Request'
+					);
 				}
 				break;
 			}
@@ -239,9 +314,12 @@ async function handleWebSocketMessage(msgObject: WebsocketMessage) {
 
 					let title = `${username} sent you a message!`;
 
-					await sendNotif(title, msg.message);
-
-					await addManualLog('Message', title, msg.senderUserId);
+					await notifyWithRules(
+						{ eventType: msg.type, userId: msg.senderUserId },
+						title,
+						msg.message,
+						'Message'
+					);
 				}
 				break;
 			}
@@ -251,9 +329,12 @@ async function handleWebSocketMessage(msgObject: WebsocketMessage) {
 
 					let title = `${username} responded to your invite request!`;
 
-					await sendNotif(title, msg.message);
-
-					await addManualLog('Invite Response', title, msg.senderUserId);
+					await notifyWithRules(
+						{ eventType: msg.type, userId: msg.senderUserId },
+						title,
+						msg.message,
+						'Invite Response'
+					);
 				}
 				break;
 			}
@@ -262,6 +343,21 @@ async function handleWebSocketMessage(msgObject: WebsocketMessage) {
 				break;
 			}
 		}
+	} else if (msgObject.type === 'notification-v2') {
+		await checkNotificationPermission();
+		await checkXsoEnabled();
+		console.log('WebSocket received a v2 notification!');
+
+		let msg: NotificationV2 = JSON.parse(msgObject.content);
+		await recordNotification(msg.id, msg.type, msg.senderUserId, msg.data);
+		let title = msg.title !== '' ? msg.title : msg.message;
+
+		await notifyWithRules(
+			{ eventType: msg.type, userId: msg.senderUserId },
+			title,
+			msg.message,
+			'Notification'
+		);
 	} else if (msgObject.type === 'user-location') {
 		let msg = JSON.parse(msgObject.content);
 		let location: string = msg.location;
@@ -292,7 +388,11 @@ async function handleWebSocketMessage(msgObject: WebsocketMessage) {
 					if (currentLocation === msg.travelingToLocation) {
 						let username = await getUsernameById(msg.userId);
 						let title = `${username} is heading to your current location!`;
-						await sendNotif(title, title);
+						await notifyWithRules(
+							{ eventType: msgObject.type, userId: msg.userId, worldId: msg.worldId },
+							title,
+							title
+						);
 					}
 				}
 			}
@@ -300,19 +400,69 @@ async function handleWebSocketMessage(msgObject: WebsocketMessage) {
 		if (msg.location != '' && msg.location != 'traveling') {
 			console.log(`friend-location ${msg.user.displayName}`);
 			await updateFriendLocation(msg);
+			await checkWatchlist({
+				eventType: msgObject.type,
+				userId: msg.userId,
+				displayName: msg.user.displayName,
+				location: msg.location,
+				worldId: msg.worldId,
+				worldName: msg.world?.name ?? null,
+				status: msg.user.status
+			});
 		}
 	} else if (msgObject.type === 'friend-offline') {
 		let msg: WebsocketFriendOffline = JSON.parse(msgObject.content);
 		console.log(`friend-offline ${msg.userId}`);
 		await updateFriendOffline(msg);
+		await checkWatchlist({ eventType: msgObject.type, userId: msg.userId });
 	} else if (msgObject.type === 'friend-online') {
 		let msg: WebsocketFriendOnline = JSON.parse(msgObject.content);
 		console.log(`friend-online ${msg.user.displayName}`);
 		await updateFriendOnline(msg);
+		await checkWatchlist({
+			eventType: msgObject.type,
+			userId: msg.userId,
+			displayName: msg.user.displayName,
+			location: msg.location,
+			worldId: msg.worldId,
+			status: msg.user.status
+		});
 	} else if (msgObject.type === 'friend-active') {
 		let msg: WebsocketFriendActive = JSON.parse(msgObject.content);
 		console.log(`friend-active ${msg.user.displayName}`);
 		await updateFriendActive(msg);
+		await checkWatchlist({
+			eventType: msgObject.type,
+			userId: msg.userId,
+			displayName: msg.user.displayName,
+			status: msg.user.status
+		});
+	} else if (msgObject.type === 'friend-update') {
+		let msg: WebsocketFriendActive = JSON.parse(msgObject.content);
+		await checkWatchlist({
+			eventType: msgObject.type,
+			userId: msg.userId,
+			displayName: msg.user.displayName,
+			status: msg.user.status
+		});
+	} else if (msgObject.type === 'friend-add') {
+		let msg: WebsocketFriendAdd = JSON.parse(msgObject.content);
+		console.log(`friend-add ${msg.user.displayName}`);
+		await invoke('update_join_friend', { userId: msg.userId, friend: true });
+	} else if (msgObject.type === 'friend-delete') {
+		let msg: WebsocketFriendDelete = JSON.parse(msgObject.content);
+		console.log(`friend-delete ${msg.userId}`);
+		await invoke('update_join_friend', { userId: msg.userId, friend: false });
+	} else if (msgObject.type?.startsWith('instance-queue-')) {
+		await checkNotificationPermission();
+		await checkXsoEnabled();
+		let alert: QueueAlert | null = await invoke('handle_instance_queue_event', {
+			eventType: msgObject.type,
+			event: JSON.parse(msgObject.content)
+		});
+		if (alert !== null) {
+			await notifyWithRules({ eventType: msgObject.type }, alert.title, alert.message);
+		}
 	} else if (msgObject.type !== undefined) {
 		console.debug(`WebSocket message type is ${msgObject.type}`);
 	} else {